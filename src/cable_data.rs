@@ -0,0 +1,281 @@
+//! Structured cable catalogue, loaded from CSV instead of scattered
+//! CSA-specific `let` bindings per binary.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::resistance::ConductorMaterial;
+
+/// Installation reference method, per BS 7671 Appendix 4 Table 4A2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstallationMethod {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+}
+
+impl InstallationMethod {
+    /// Parses a single installation method letter (`A`-`F`).
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim() {
+            "A" => Some(Self::A),
+            "B" => Some(Self::B),
+            "C" => Some(Self::C),
+            "D" => Some(Self::D),
+            "E" => Some(Self::E),
+            "F" => Some(Self::F),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for InstallationMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let letter = match self {
+            Self::A => "A",
+            Self::B => "B",
+            Self::C => "C",
+            Self::D => "D",
+            Self::E => "E",
+            Self::F => "F",
+        };
+        write!(f, "{letter}")
+    }
+}
+
+/// One row of a cable catalogue: a conductor material and CSA, its rated
+/// current for a given installation method, and its base mV/A/m figure.
+#[derive(Debug, Clone, Copy)]
+pub struct CableType {
+    pub material: ConductorMaterial,
+    pub csa_mm2: f64,
+    pub installation_method: InstallationMethod,
+    pub rated_current_a: f64,
+    /// Base mV/A/m figure, tabulated at [`APPENDIX_FOUR_REFERENCE_TEMP_C`]
+    /// for the bundled defaults, or supplied directly by a `--cable-data`
+    /// CSV row. Scale this by a temperature-correction ratio rather than
+    /// discarding it in favour of a figure recomputed from scratch, so a
+    /// user-supplied catalogue's own voltage-drop figures are respected.
+    pub mv_per_amp_per_meter: f64,
+}
+
+/// Conductor operating temperature, in °C, that the bundled Appendix 4
+/// Table 4D4B mV/A/m figures are tabulated at (70°C thermoplastic
+/// insulation).
+pub const APPENDIX_FOUR_REFERENCE_TEMP_C: f64 = 70.0;
+
+/// Number of loaded cores, which affects a cable's tabulated current rating
+/// (more cores bunched in one cable means more self-generated heat for the
+/// same CSA) per BS 7671 Appendix 4 Table 4D4A.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoreCount {
+    Two,
+    ThreeOrFour,
+}
+
+/// One row of the BS 7671 Appendix 4 tables: tabulated current rating for a
+/// material/core-count/CSA combination across installation methods C, D and
+/// E (Table 4D4A), and the mV/A/m voltage-drop figure (Table 4D4B), which
+/// BS 7671 tabulates independently of installation method.
+struct AppendixFourRow {
+    material: ConductorMaterial,
+    core_count: CoreCount,
+    csa_mm2: f64,
+    rated_current_method_c_a: f64,
+    rated_current_method_d_a: f64,
+    rated_current_method_e_a: f64,
+    mv_per_amp_per_meter: f64,
+}
+
+/// Bundled BS 7671 Appendix 4 Table 4D4A/4D4B data for 70°C thermoplastic
+/// (PVC) insulated SWA cable, covering the CSAs commonly used for garage
+/// and outbuilding sub-mains.
+fn appendix_four_table() -> Vec<AppendixFourRow> {
+    vec![
+        AppendixFourRow { material: ConductorMaterial::Copper, core_count: CoreCount::Two, csa_mm2: 1.5, rated_current_method_c_a: 32.0, rated_current_method_d_a: 26.0, rated_current_method_e_a: 30.0, mv_per_amp_per_meter: 29.0 },
+        AppendixFourRow { material: ConductorMaterial::Copper, core_count: CoreCount::Two, csa_mm2: 2.5, rated_current_method_c_a: 43.0, rated_current_method_d_a: 34.0, rated_current_method_e_a: 40.0, mv_per_amp_per_meter: 18.0 },
+        AppendixFourRow { material: ConductorMaterial::Copper, core_count: CoreCount::Two, csa_mm2: 4.0, rated_current_method_c_a: 57.0, rated_current_method_d_a: 44.0, rated_current_method_e_a: 53.0, mv_per_amp_per_meter: 11.0 },
+        AppendixFourRow { material: ConductorMaterial::Copper, core_count: CoreCount::Two, csa_mm2: 6.0, rated_current_method_c_a: 73.0, rated_current_method_d_a: 56.0, rated_current_method_e_a: 68.0, mv_per_amp_per_meter: 7.3 },
+        AppendixFourRow { material: ConductorMaterial::Copper, core_count: CoreCount::Two, csa_mm2: 10.0, rated_current_method_c_a: 98.0, rated_current_method_d_a: 71.0, rated_current_method_e_a: 89.0, mv_per_amp_per_meter: 4.4 },
+        AppendixFourRow { material: ConductorMaterial::Copper, core_count: CoreCount::Two, csa_mm2: 16.0, rated_current_method_c_a: 129.0, rated_current_method_d_a: 91.0, rated_current_method_e_a: 117.0, mv_per_amp_per_meter: 2.8 },
+        AppendixFourRow { material: ConductorMaterial::Copper, core_count: CoreCount::Two, csa_mm2: 25.0, rated_current_method_c_a: 164.0, rated_current_method_d_a: 116.0, rated_current_method_e_a: 150.0, mv_per_amp_per_meter: 1.75 },
+        AppendixFourRow { material: ConductorMaterial::Copper, core_count: CoreCount::Two, csa_mm2: 35.0, rated_current_method_c_a: 198.0, rated_current_method_d_a: 139.0, rated_current_method_e_a: 183.0, mv_per_amp_per_meter: 1.25 },
+        AppendixFourRow { material: ConductorMaterial::Copper, core_count: CoreCount::ThreeOrFour, csa_mm2: 1.5, rated_current_method_c_a: 29.0, rated_current_method_d_a: 24.0, rated_current_method_e_a: 27.0, mv_per_amp_per_meter: 29.0 },
+        AppendixFourRow { material: ConductorMaterial::Copper, core_count: CoreCount::ThreeOrFour, csa_mm2: 2.5, rated_current_method_c_a: 39.0, rated_current_method_d_a: 31.0, rated_current_method_e_a: 36.0, mv_per_amp_per_meter: 18.0 },
+        AppendixFourRow { material: ConductorMaterial::Copper, core_count: CoreCount::ThreeOrFour, csa_mm2: 4.0, rated_current_method_c_a: 52.0, rated_current_method_d_a: 40.0, rated_current_method_e_a: 48.0, mv_per_amp_per_meter: 11.0 },
+        AppendixFourRow { material: ConductorMaterial::Copper, core_count: CoreCount::ThreeOrFour, csa_mm2: 6.0, rated_current_method_c_a: 67.0, rated_current_method_d_a: 51.0, rated_current_method_e_a: 62.0, mv_per_amp_per_meter: 7.3 },
+        AppendixFourRow { material: ConductorMaterial::Copper, core_count: CoreCount::ThreeOrFour, csa_mm2: 10.0, rated_current_method_c_a: 90.0, rated_current_method_d_a: 65.0, rated_current_method_e_a: 82.0, mv_per_amp_per_meter: 4.4 },
+        AppendixFourRow { material: ConductorMaterial::Copper, core_count: CoreCount::ThreeOrFour, csa_mm2: 16.0, rated_current_method_c_a: 119.0, rated_current_method_d_a: 84.0, rated_current_method_e_a: 108.0, mv_per_amp_per_meter: 2.8 },
+        AppendixFourRow { material: ConductorMaterial::Copper, core_count: CoreCount::ThreeOrFour, csa_mm2: 25.0, rated_current_method_c_a: 151.0, rated_current_method_d_a: 107.0, rated_current_method_e_a: 138.0, mv_per_amp_per_meter: 1.75 },
+        AppendixFourRow { material: ConductorMaterial::Copper, core_count: CoreCount::ThreeOrFour, csa_mm2: 35.0, rated_current_method_c_a: 182.0, rated_current_method_d_a: 128.0, rated_current_method_e_a: 168.0, mv_per_amp_per_meter: 1.25 },
+        AppendixFourRow { material: ConductorMaterial::Aluminium, core_count: CoreCount::Two, csa_mm2: 2.5, rated_current_method_c_a: 33.0, rated_current_method_d_a: 27.0, rated_current_method_e_a: 31.0, mv_per_amp_per_meter: 29.5 },
+        AppendixFourRow { material: ConductorMaterial::Aluminium, core_count: CoreCount::Two, csa_mm2: 4.0, rated_current_method_c_a: 44.0, rated_current_method_d_a: 34.0, rated_current_method_e_a: 41.0, mv_per_amp_per_meter: 18.0 },
+        AppendixFourRow { material: ConductorMaterial::Aluminium, core_count: CoreCount::Two, csa_mm2: 6.0, rated_current_method_c_a: 57.0, rated_current_method_d_a: 44.0, rated_current_method_e_a: 53.0, mv_per_amp_per_meter: 12.0 },
+        AppendixFourRow { material: ConductorMaterial::Aluminium, core_count: CoreCount::Two, csa_mm2: 10.0, rated_current_method_c_a: 76.0, rated_current_method_d_a: 55.0, rated_current_method_e_a: 69.0, mv_per_amp_per_meter: 7.2 },
+        AppendixFourRow { material: ConductorMaterial::Aluminium, core_count: CoreCount::Two, csa_mm2: 16.0, rated_current_method_c_a: 100.0, rated_current_method_d_a: 71.0, rated_current_method_e_a: 91.0, mv_per_amp_per_meter: 4.6 },
+        AppendixFourRow { material: ConductorMaterial::Aluminium, core_count: CoreCount::Two, csa_mm2: 25.0, rated_current_method_c_a: 127.0, rated_current_method_d_a: 90.0, rated_current_method_e_a: 117.0, mv_per_amp_per_meter: 2.9 },
+        AppendixFourRow { material: ConductorMaterial::Aluminium, core_count: CoreCount::Two, csa_mm2: 35.0, rated_current_method_c_a: 154.0, rated_current_method_d_a: 108.0, rated_current_method_e_a: 142.0, mv_per_amp_per_meter: 2.1 },
+    ]
+}
+
+/// Looks up the tabulated current rating (for `method`) and mV/A/m figure
+/// for `material`/`core_count`/`csa_mm2` from the Appendix 4 tables.
+pub fn lookup_appendix_four(
+    material: ConductorMaterial,
+    core_count: CoreCount,
+    csa_mm2: f64,
+    method: InstallationMethod,
+) -> Option<(f64, f64)> {
+    let row = appendix_four_table().into_iter().find(|row| {
+        row.material == material && row.core_count == core_count && (row.csa_mm2 - csa_mm2).abs() < 1e-9
+    })?;
+    let rated_current_a = match method {
+        InstallationMethod::C => row.rated_current_method_c_a,
+        InstallationMethod::D => row.rated_current_method_d_a,
+        InstallationMethod::E => row.rated_current_method_e_a,
+        InstallationMethod::A | InstallationMethod::B | InstallationMethod::F => return None,
+    };
+    Some((rated_current_a, row.mv_per_amp_per_meter))
+}
+
+/// Builds a catalogue covering every CSA the Appendix 4 tables have for
+/// `material`/`core_count` at the given installation `method`, rather than
+/// one fixed set of hard-coded sizes.
+pub fn catalogue_for(
+    material: ConductorMaterial,
+    core_count: CoreCount,
+    method: InstallationMethod,
+) -> Vec<CableType> {
+    appendix_four_table()
+        .into_iter()
+        .filter(|row| row.material == material && row.core_count == core_count)
+        .filter_map(|row| {
+            let (rated_current_a, mv_per_amp_per_meter) =
+                lookup_appendix_four(material, core_count, row.csa_mm2, method)?;
+            Some(CableType {
+                material,
+                csa_mm2: row.csa_mm2,
+                installation_method: method,
+                rated_current_a,
+                mv_per_amp_per_meter,
+            })
+        })
+        .collect()
+}
+
+/// Parses a cable catalogue from CSV text with columns
+/// `material,csa_mm2,installation_method,rated_current_a,mv_per_amp_per_meter`.
+/// Rows that fail to parse are skipped rather than aborting the whole load.
+pub fn parse_csv(csv: &str) -> Vec<CableType> {
+    csv.lines()
+        .skip(1) // header row
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split(',').collect();
+            if fields.len() != 5 {
+                return None;
+            }
+            let material = match fields[0].trim() {
+                "copper" => ConductorMaterial::Copper,
+                "aluminium" => ConductorMaterial::Aluminium,
+                _ => return None,
+            };
+            Some(CableType {
+                material,
+                csa_mm2: fields[1].trim().parse().ok()?,
+                installation_method: InstallationMethod::parse(fields[2])?,
+                rated_current_a: fields[3].trim().parse().ok()?,
+                mv_per_amp_per_meter: fields[4].trim().parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+/// Loads a cable catalogue from a CSV file at `path`.
+pub fn load_csv(path: &Path) -> io::Result<Vec<CableType>> {
+    let content = fs::read_to_string(path)?;
+    Ok(parse_csv(&content))
+}
+
+/// Bundled BS 7671 Appendix 4 default catalogue for 2-core SWA copper
+/// cables (Table 4D4A current ratings, Table 4D4B voltage-drop figures,
+/// Method D - buried), covering the 10/16/25 mm² sizes this tool has
+/// historically hard-coded.
+pub fn default_catalogue() -> Vec<CableType> {
+    catalogue_for(ConductorMaterial::Copper, CoreCount::Two, InstallationMethod::D)
+        .into_iter()
+        .filter(|cable| [10.0, 16.0, 25.0].contains(&cable.csa_mm2))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_catalogue_has_three_entries() {
+        assert_eq!(default_catalogue().len(), 3);
+    }
+
+    #[test]
+    fn parse_csv_reads_valid_rows_and_skips_bad_ones() {
+        let csv = "material,csa_mm2,installation_method,rated_current_a,mv_per_amp_per_meter\n\
+                    copper,10.0,D,71.0,4.4\n\
+                    not,a,valid,row\n\
+                    aluminium,16.0,C,68.0,2.9\n";
+        let entries = parse_csv(csv);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].csa_mm2, 10.0);
+        assert_eq!(entries[1].material, ConductorMaterial::Aluminium);
+    }
+
+    #[test]
+    fn lookup_matches_default_catalogue_figures() {
+        let (rated_current_a, mv_per_amp_per_meter) = lookup_appendix_four(
+            ConductorMaterial::Copper,
+            CoreCount::Two,
+            10.0,
+            InstallationMethod::D,
+        )
+        .unwrap();
+        assert_eq!(rated_current_a, 71.0);
+        assert_eq!(mv_per_amp_per_meter, 4.4);
+    }
+
+    #[test]
+    fn lookup_returns_none_for_untabulated_method() {
+        assert!(lookup_appendix_four(
+            ConductorMaterial::Copper,
+            CoreCount::Two,
+            10.0,
+            InstallationMethod::A,
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn catalogue_for_covers_every_tabulated_csa() {
+        let catalogue = catalogue_for(ConductorMaterial::Copper, CoreCount::Two, InstallationMethod::C);
+        assert_eq!(catalogue.len(), 8);
+        assert!(catalogue.iter().all(|cable| cable.installation_method == InstallationMethod::C));
+    }
+
+    #[test]
+    fn three_or_four_core_rating_is_lower_than_two_core_for_the_same_csa() {
+        let two_core =
+            lookup_appendix_four(ConductorMaterial::Copper, CoreCount::Two, 10.0, InstallationMethod::D)
+                .unwrap();
+        let three_core = lookup_appendix_four(
+            ConductorMaterial::Copper,
+            CoreCount::ThreeOrFour,
+            10.0,
+            InstallationMethod::D,
+        )
+        .unwrap();
+        assert!(three_core.0 < two_core.0);
+    }
+}
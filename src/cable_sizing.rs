@@ -0,0 +1,98 @@
+//! Full BS 7671 Appendix 4 cable-sizing workflow.
+//!
+//! Ties voltage drop and adiabatic sizing together with the standard
+//! selection procedure: pick the smallest tabulated current-carrying
+//! capacity `It` that, once derated for the installation conditions,
+//! still exceeds the protective device rating: `It >= In / (Ca·Cg·Ci·Cf)`.
+
+use crate::cable_data::CableType;
+use crate::derating::DeratingFactors;
+
+/// Result of sizing a cable against BS 7671 Appendix 4 correction factors.
+#[derive(Debug, Clone, Copy)]
+pub struct CableSizingResult {
+    pub design_current_ib: f64,
+    pub device_rating_in: f64,
+    /// Minimum tabulated current required: `In / (Ca·Cg·Ci·Cf)`.
+    pub minimum_required_it: f64,
+    pub selected_csa_mm2: f64,
+    pub selected_tabulated_it: f64,
+    /// Derated installed capacity of the selected cable: `It·Ca·Cg·Ci·Cf`.
+    pub derated_iz: f64,
+}
+
+/// No cable in the catalogue has a tabulated current large enough to
+/// satisfy `It >= In / (Ca·Cg·Ci·Cf)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoSuitableCable {
+    pub minimum_required_it: f64,
+}
+
+/// Selects the smallest cable in `catalogue` whose tabulated current `It`
+/// satisfies `It >= In / (Ca·Cg·Ci·Cf)` (`Cf` here is `DeratingFactors::cc`,
+/// the BS 3036 rewirable-fuse factor), and reports its derated `Iz` so a
+/// caller can verify `Ib <= In <= Iz`.
+pub fn size_cable(
+    design_current_ib: f64,
+    device_rating_in: f64,
+    derating: &DeratingFactors,
+    catalogue: &[CableType],
+) -> Result<CableSizingResult, NoSuitableCable> {
+    let correction = derating.ca() * derating.cg() * derating.ci() * derating.cc();
+    let minimum_required_it = device_rating_in / correction;
+
+    catalogue
+        .iter()
+        .filter(|cable| cable.rated_current_a >= minimum_required_it)
+        .min_by(|a, b| a.rated_current_a.partial_cmp(&b.rated_current_a).unwrap())
+        .map(|cable| CableSizingResult {
+            design_current_ib,
+            device_rating_in,
+            minimum_required_it,
+            selected_csa_mm2: cable.csa_mm2,
+            selected_tabulated_it: cable.rated_current_a,
+            derated_iz: derating.derate(cable.rated_current_a),
+        })
+        .ok_or(NoSuitableCable { minimum_required_it })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cable_data::default_catalogue;
+    use crate::derating::InsulationType;
+
+    fn unity_derating() -> DeratingFactors {
+        DeratingFactors {
+            insulation: InsulationType::Pvc70,
+            ambient_c: 30.0,
+            circuit_count: 1,
+            thermal_insulation_enclosed_length_m: 0.0,
+            semi_enclosed_fuse: false,
+        }
+    }
+
+    #[test]
+    fn selects_smallest_cable_meeting_the_device_rating() {
+        let result = size_cable(32.0, 32.0, &unity_derating(), &default_catalogue()).unwrap();
+        assert_eq!(result.selected_csa_mm2, 10.0);
+        assert!(result.derated_iz >= result.device_rating_in);
+    }
+
+    #[test]
+    fn derating_pushes_selection_to_a_larger_cable() {
+        let derated = DeratingFactors {
+            circuit_count: 3,
+            ambient_c: 40.0,
+            ..unity_derating()
+        };
+        let result = size_cable(32.0, 63.0, &derated, &default_catalogue()).unwrap();
+        assert_eq!(result.selected_csa_mm2, 25.0);
+    }
+
+    #[test]
+    fn no_cable_large_enough_is_reported() {
+        let result = size_cable(200.0, 200.0, &unity_derating(), &default_catalogue());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,171 @@
+//! BS 7671 Appendix 4 current-carrying capacity derating factors.
+//!
+//! Replaces a fixed tabulated ampacity (e.g. "71A") with the installed
+//! capacity `Iz` after applying the correction factors for the actual
+//! installation conditions: `Iz = It · Ca · Cg · Ci · Cc`.
+
+/// Insulation type, used to key the ambient-temperature correction table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InsulationType {
+    /// General purpose PVC, rated to 70°C conductor operating temperature.
+    Pvc70,
+    /// XLPE / thermosetting, rated to 90°C conductor operating temperature.
+    Xlpe90,
+}
+
+fn interpolate(table: &[(f64, f64)], x: f64) -> f64 {
+    let first = table.first().expect("correction table must not be empty");
+    let last = table.last().expect("correction table must not be empty");
+    if x <= first.0 {
+        return first.1;
+    }
+    if x >= last.0 {
+        return last.1;
+    }
+    for window in table.windows(2) {
+        let (x0, y0) = window[0];
+        let (x1, y1) = window[1];
+        if x >= x0 && x <= x1 {
+            let frac = (x - x0) / (x1 - x0);
+            return y0 + frac * (y1 - y0);
+        }
+    }
+    last.1
+}
+
+/// Ambient-temperature correction factor `Ca`, from BS 7671 Table 4B1,
+/// interpolated between tabulated points and clamped to the table's
+/// extremes. Reference ambient is 30°C (factor 1.00).
+pub fn ambient_temperature_factor(insulation: InsulationType, ambient_c: f64) -> f64 {
+    let table: &[(f64, f64)] = match insulation {
+        InsulationType::Pvc70 => &[
+            (25.0, 1.03),
+            (30.0, 1.00),
+            (35.0, 0.94),
+            (40.0, 0.87),
+            (45.0, 0.79),
+            (50.0, 0.71),
+            (55.0, 0.61),
+            (60.0, 0.50),
+        ],
+        InsulationType::Xlpe90 => &[
+            (25.0, 1.02),
+            (30.0, 1.00),
+            (35.0, 0.96),
+            (40.0, 0.91),
+            (45.0, 0.87),
+            (50.0, 0.82),
+            (55.0, 0.76),
+            (60.0, 0.71),
+        ],
+    };
+    interpolate(table, ambient_c)
+}
+
+/// Grouping correction factor `Cg`, from BS 7671 Table 4C1, for
+/// `circuit_count` circuits/cables bunched together (single layer, touching).
+pub fn grouping_factor(circuit_count: u32) -> f64 {
+    match circuit_count {
+        0 | 1 => 1.00,
+        2 => 0.80,
+        3 => 0.70,
+        4 => 0.65,
+        5 => 0.60,
+        6 => 0.57,
+        7..=9 => 0.54,
+        _ => 0.50,
+    }
+}
+
+/// Thermal-insulation correction factor `Ci` (BS 7671 Regulation 523.9):
+/// 0.5 when a cable is fully surrounded by thermal insulation over more
+/// than 0.5m, interpolated linearly for shorter enclosed lengths.
+pub fn thermal_insulation_factor(enclosed_length_m: f64) -> f64 {
+    if enclosed_length_m <= 0.0 {
+        1.0
+    } else if enclosed_length_m >= 0.5 {
+        0.5
+    } else {
+        1.0 - enclosed_length_m
+    }
+}
+
+/// Correction factor `Cc` applied for a BS 3036 semi-enclosed rewirable
+/// fuse (0.725); 1.0 otherwise.
+pub fn semi_enclosed_fuse_factor(semi_enclosed_fuse: bool) -> f64 {
+    if semi_enclosed_fuse {
+        0.725
+    } else {
+        1.0
+    }
+}
+
+/// Installation conditions for one cable run, used to derate its tabulated
+/// current-carrying capacity.
+#[derive(Debug, Clone, Copy)]
+pub struct DeratingFactors {
+    pub insulation: InsulationType,
+    pub ambient_c: f64,
+    pub circuit_count: u32,
+    /// Length of run fully surrounded by thermal insulation, in m.
+    pub thermal_insulation_enclosed_length_m: f64,
+    pub semi_enclosed_fuse: bool,
+}
+
+impl DeratingFactors {
+    pub fn ca(&self) -> f64 {
+        ambient_temperature_factor(self.insulation, self.ambient_c)
+    }
+
+    pub fn cg(&self) -> f64 {
+        grouping_factor(self.circuit_count)
+    }
+
+    pub fn ci(&self) -> f64 {
+        thermal_insulation_factor(self.thermal_insulation_enclosed_length_m)
+    }
+
+    pub fn cc(&self) -> f64 {
+        semi_enclosed_fuse_factor(self.semi_enclosed_fuse)
+    }
+
+    /// Derated installed current capacity `Iz = It · Ca · Cg · Ci · Cc`.
+    pub fn derate(&self, tabulated_current_a: f64) -> f64 {
+        tabulated_current_a * self.ca() * self.cg() * self.ci() * self.cc()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference_ambient_gives_unity_factor() {
+        assert_eq!(ambient_temperature_factor(InsulationType::Pvc70, 30.0), 1.00);
+    }
+
+    #[test]
+    fn single_circuit_has_no_grouping_penalty() {
+        assert_eq!(grouping_factor(1), 1.00);
+        assert!(grouping_factor(4) < 1.00);
+    }
+
+    #[test]
+    fn thermal_insulation_floors_at_half_metre() {
+        assert_eq!(thermal_insulation_factor(1.0), 0.5);
+        assert_eq!(thermal_insulation_factor(0.0), 1.0);
+        assert!((thermal_insulation_factor(0.25) - 0.75).abs() < 1e-9);
+    }
+
+    #[test]
+    fn derated_capacity_is_below_tabulated_in_hot_grouped_conditions() {
+        let factors = DeratingFactors {
+            insulation: InsulationType::Pvc70,
+            ambient_c: 45.0,
+            circuit_count: 4,
+            thermal_insulation_enclosed_length_m: 0.0,
+            semi_enclosed_fuse: false,
+        };
+        assert!(factors.derate(71.0) < 71.0);
+    }
+}
@@ -0,0 +1,206 @@
+//! Earth fault loop impedance model.
+//!
+//! Derives the prospective earth fault current from circuit impedance
+//! instead of taking it as a fixed assumed value, so the adiabatic and
+//! disconnection-time checks are driven by the actual installation.
+
+use crate::protective_device::ProtectiveDevice;
+use crate::resistance::ResistanceModel;
+
+/// Nominal phase voltage, in V.
+pub const U0: f64 = 230.0;
+
+/// Voltage factor applied to `U0` when computing prospective fault current,
+/// accounting for supply voltage variation (BS 7671 Appendix 3).
+pub const CMIN: f64 = 0.95;
+
+/// Typical external earth loop impedance for a PME / TN-C-S supply, in Ω.
+/// TT systems have a much higher `Ze`, dominated by the electrode resistance.
+pub const ZE_TNCS_DEFAULT: f64 = 0.35;
+
+/// Source/transformer short-circuit rating, used to optionally fold the
+/// supply's own impedance into the fault loop.
+#[derive(Debug, Clone, Copy)]
+pub struct TransformerSource {
+    pub rated_kva: f64,
+    pub impedance_percent: f64,
+}
+
+impl TransformerSource {
+    /// Transformer short-circuit impedance referred to the secondary:
+    /// `Zcc = U² / Scc`, in Ω.
+    pub fn impedance_ohms(&self, line_voltage: f64) -> f64 {
+        let scc_va = self.rated_kva * 1000.0 / (self.impedance_percent / 100.0);
+        line_voltage * line_voltage / scc_va
+    }
+}
+
+/// Inputs for the earth fault loop `Zs = Ze + (R1 + R2)`.
+#[derive(Debug, Clone, Copy)]
+pub struct EarthFaultLoop {
+    /// External earth loop impedance upstream of the installation, in Ω.
+    pub external_impedance_ze: f64,
+    /// Line conductor resistance model, evaluated at its operating temperature.
+    pub line_resistance: ResistanceModel,
+    /// Circuit protective conductor resistance model, evaluated at its
+    /// operating temperature at the moment of fault.
+    pub cpc_resistance: ResistanceModel,
+    /// Conductor run length, in m.
+    pub length_m: f64,
+    /// Optional source transformer impedance, folded into `Zs` as `Zcc`.
+    pub transformer_source: Option<TransformerSource>,
+}
+
+impl EarthFaultLoop {
+    /// `R1 + R2`: summed line and CPC resistance over the run length, in Ω.
+    pub fn r1_plus_r2(&self) -> f64 {
+        (self.line_resistance.r_dc() + self.cpc_resistance.r_dc()) * self.length_m
+    }
+
+    /// `Zs = Ze + Zcc + (R1 + R2)`, in Ω.
+    pub fn zs(&self) -> f64 {
+        let zcc = self
+            .transformer_source
+            .map(|source| source.impedance_ohms(U0 * 3f64.sqrt()))
+            .unwrap_or(0.0);
+        self.external_impedance_ze + zcc + self.r1_plus_r2()
+    }
+
+    /// Prospective earth fault current `Ief = Cmin·U0 / Zs`, in amperes.
+    pub fn prospective_fault_current(&self) -> f64 {
+        CMIN * U0 / self.zs()
+    }
+
+    /// Checks whether `device` disconnects this loop's prospective fault
+    /// current within the maximum time permitted for `circuit_type`.
+    pub fn verify_disconnection_time(
+        &self,
+        device: &ProtectiveDevice,
+        circuit_type: CircuitType,
+    ) -> DisconnectionCheck {
+        let prospective_fault_current_a = self.prospective_fault_current();
+        let actual_disconnection_time_s = device
+            .disconnection_time(prospective_fault_current_a)
+            .unwrap_or(f64::INFINITY);
+        let max_permitted_disconnection_time_s = circuit_type.max_disconnection_time_s();
+        DisconnectionCheck {
+            prospective_fault_current_a,
+            actual_disconnection_time_s,
+            max_permitted_disconnection_time_s,
+            disconnects_in_time: actual_disconnection_time_s <= max_permitted_disconnection_time_s,
+        }
+    }
+}
+
+/// Circuit type, used to determine the maximum permitted disconnection
+/// time per BS 7671 Regulation 411.3.2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitType {
+    /// Final circuit not exceeding 32A on a TN system: 0.4s.
+    FinalCircuitTnUpTo32A,
+    /// Distribution circuit, or any other final circuit: 5s.
+    Distribution,
+}
+
+impl CircuitType {
+    /// Maximum permitted disconnection time, in seconds.
+    pub fn max_disconnection_time_s(&self) -> f64 {
+        match self {
+            CircuitType::FinalCircuitTnUpTo32A => 0.4,
+            CircuitType::Distribution => 5.0,
+        }
+    }
+}
+
+/// Result of checking a loop's disconnection time against the maximum
+/// permitted for its circuit type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DisconnectionCheck {
+    pub prospective_fault_current_a: f64,
+    pub actual_disconnection_time_s: f64,
+    pub max_permitted_disconnection_time_s: f64,
+    pub disconnects_in_time: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resistance::ConductorMaterial;
+
+    fn conductor(csa_mm2: f64) -> ResistanceModel {
+        ResistanceModel {
+            material: ConductorMaterial::Copper,
+            csa_mm2,
+            operating_temp_c: 70.0,
+            frequency_hz: 50.0,
+            spacing_mm: 20.0,
+        }
+    }
+
+    #[test]
+    fn zs_is_ze_plus_conductor_resistance() {
+        let loop_impedance = EarthFaultLoop {
+            external_impedance_ze: ZE_TNCS_DEFAULT,
+            line_resistance: conductor(10.0),
+            cpc_resistance: conductor(10.0),
+            length_m: 40.0,
+            transformer_source: None,
+        };
+        assert!(loop_impedance.zs() > ZE_TNCS_DEFAULT);
+    }
+
+    #[test]
+    fn longer_runs_reduce_fault_current() {
+        let short_run = EarthFaultLoop {
+            external_impedance_ze: ZE_TNCS_DEFAULT,
+            line_resistance: conductor(10.0),
+            cpc_resistance: conductor(10.0),
+            length_m: 10.0,
+            transformer_source: None,
+        };
+        let long_run = EarthFaultLoop {
+            length_m: 100.0,
+            ..short_run
+        };
+        assert!(long_run.prospective_fault_current() < short_run.prospective_fault_current());
+    }
+
+    #[test]
+    fn transformer_source_increases_zs() {
+        let without_source = EarthFaultLoop {
+            external_impedance_ze: ZE_TNCS_DEFAULT,
+            line_resistance: conductor(10.0),
+            cpc_resistance: conductor(10.0),
+            length_m: 40.0,
+            transformer_source: None,
+        };
+        let with_source = EarthFaultLoop {
+            transformer_source: Some(TransformerSource {
+                rated_kva: 500.0,
+                impedance_percent: 5.0,
+            }),
+            ..without_source
+        };
+        assert!(with_source.zs() > without_source.zs());
+    }
+
+    #[test]
+    fn low_impedance_loop_disconnects_in_time() {
+        use crate::protective_device::ProtectiveDevice;
+
+        let loop_impedance = EarthFaultLoop {
+            external_impedance_ze: ZE_TNCS_DEFAULT,
+            line_resistance: conductor(10.0),
+            cpc_resistance: conductor(10.0),
+            length_m: 40.0,
+            transformer_source: None,
+        };
+        // A BS 88-3 100A fuse only reaches ~424A (Zs≈0.515Ω) at 10mm²/40m,
+        // which takes ~14.9s on its curve - too slow for the 5s limit. The
+        // smaller BS 88-2 63A fuse trips the same prospective fault current
+        // in ~1.8s, so use that to exercise the "disconnects in time" path.
+        let device = ProtectiveDevice::bs88_2_63a();
+        let check = loop_impedance.verify_disconnection_time(&device, CircuitType::Distribution);
+        assert!(check.disconnects_in_time);
+    }
+}
@@ -0,0 +1,107 @@
+//! Adiabatic equation k factor (BS 7671 Table 54.2), derived from conductor
+//! material and initial/final temperature rather than read off one table
+//! row, so a conductor run at a different operating temperature or exposed
+//! to a different insulation's final temperature gets its own figure.
+//!
+//! `k = sqrt( Qc·(β+20)/ρ20 · ln((β+θf)/(β+θi)) )` (IEC 60364-5-54 Annex A).
+
+use crate::resistance::ConductorMaterial;
+
+/// Material constants for the k-factor formula: `β` is the reciprocal of
+/// the temperature coefficient of resistivity at 0°C (in °C), `qc` the
+/// volumetric heat capacity, `rho20` the resistivity at 20°C.
+struct KFactorConstants {
+    beta_c: f64,
+    qc: f64,
+    rho20: f64,
+}
+
+impl ConductorMaterial {
+    fn k_factor_constants(&self) -> KFactorConstants {
+        // IEC 60364-5-54 Annex A tabulates qc in J/(K·mm³) and rho20 in
+        // Ω·mm, a factor of 1000 off `resistivity_20c()`'s Ω·m -
+        // derive rho20 from the one resistivity table in resistance.rs
+        // instead of a second, independently-rounded copy of it.
+        let rho20 = self.resistivity_20c() * 1000.0;
+        match self {
+            ConductorMaterial::Copper => KFactorConstants {
+                beta_c: 234.5,
+                qc: 3.45e-3,
+                rho20,
+            },
+            ConductorMaterial::Aluminium => KFactorConstants {
+                beta_c: 228.0,
+                qc: 2.5e-3,
+                rho20,
+            },
+            ConductorMaterial::Steel => KFactorConstants {
+                beta_c: 202.0,
+                qc: 3.8e-3,
+                rho20,
+            },
+        }
+    }
+}
+
+/// Insulation type, determining the maximum permitted conductor
+/// temperature under fault (BS 7671 Table 54.3).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InsulationFinalTemp {
+    /// General-purpose PVC, final temperature 160°C.
+    Pvc,
+    /// XLPE/EPR (90°C rated), final temperature 250°C.
+    Xlpe,
+    /// 85°C rubber, final temperature 220°C.
+    Rubber85,
+}
+
+impl InsulationFinalTemp {
+    /// Maximum permitted conductor temperature under fault, in °C.
+    pub fn final_temp_c(&self) -> f64 {
+        match self {
+            InsulationFinalTemp::Pvc => 160.0,
+            InsulationFinalTemp::Xlpe => 250.0,
+            InsulationFinalTemp::Rubber85 => 220.0,
+        }
+    }
+}
+
+/// Computes the adiabatic k factor for `material` heating from
+/// `initial_temp_c` to `final_temp_c`, replacing a fixed Table 54.2 row
+/// with the actual operating and fault temperatures.
+pub fn k_factor(material: ConductorMaterial, initial_temp_c: f64, final_temp_c: f64) -> f64 {
+    let c = material.k_factor_constants();
+    let term = c.qc * (c.beta_c + 20.0) / c.rho20;
+    let ratio = (c.beta_c + final_temp_c) / (c.beta_c + initial_temp_c);
+    (term * ratio.ln()).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn copper_30_to_160_matches_table_54_2() {
+        let k = k_factor(ConductorMaterial::Copper, 30.0, 160.0);
+        assert!((k - 143.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn aluminium_30_to_160_matches_table_54_2() {
+        let k = k_factor(ConductorMaterial::Aluminium, 30.0, 160.0);
+        assert!((k - 95.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn steel_30_to_160_matches_table_54_2() {
+        let k = k_factor(ConductorMaterial::Steel, 30.0, 160.0);
+        assert!((k - 52.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn higher_initial_temperature_reduces_k() {
+        let cold_start = k_factor(ConductorMaterial::Copper, 30.0, 160.0);
+        let hot_start = k_factor(ConductorMaterial::Copper, 70.0, 160.0);
+        assert!(hot_start < cold_start);
+    }
+}
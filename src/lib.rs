@@ -0,0 +1,14 @@
+//! Shared calculation library for the `electric` cable-sizing tools.
+//!
+//! The binaries in `src/bin` are thin CLI/report wrappers around the
+//! engineering models collected here, so the same BS 7671 / IEC formulae
+//! stay in one place instead of being re-derived per binary.
+
+pub mod cable_data;
+pub mod cable_sizing;
+pub mod derating;
+pub mod fault_loop;
+pub mod k_factor;
+pub mod lifecycle_cost;
+pub mod protective_device;
+pub mod resistance;
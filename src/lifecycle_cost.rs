@@ -0,0 +1,145 @@
+//! Net-present-value lifecycle costing for cable-size selection.
+//!
+//! Replaces a single-year "Investment" saving figure with a discounted
+//! comparison over the cable's service life, so a larger CSA's extra
+//! capital cost can be weighed against the energy it saves over time
+//! rather than a single year's tariff difference.
+
+/// Discount-rate and tariff assumptions for a lifecycle cost comparison.
+#[derive(Debug, Clone, Copy)]
+pub struct LifecycleCost {
+    /// Number of years over which energy-loss cost is discounted and summed.
+    pub analysis_years: u32,
+    /// Annual discount rate, as a fraction (e.g. 0.064 for 6.4%).
+    pub discount_rate: f64,
+    /// Annual energy tariff escalation rate, as a fraction (e.g. 0.017 for 1.7%).
+    pub energy_escalation_rate: f64,
+    /// Energy tariff, in pence per kWh.
+    pub tariff_p_per_kwh: f64,
+}
+
+impl Default for LifecycleCost {
+    fn default() -> Self {
+        Self {
+            analysis_years: 25,
+            discount_rate: 0.064,
+            energy_escalation_rate: 0.017,
+            tariff_p_per_kwh: 14.14,
+        }
+    }
+}
+
+impl LifecycleCost {
+    /// NPV of the energy-loss cost over `analysis_years`:
+    /// `Σ_{t=1..N} (loss_kwh · tariff · (1+escalation)^t) / (1+discount)^t`
+    pub fn npv_energy_cost(&self, yearly_loss_kwh: f64) -> f64 {
+        let tariff_pounds_per_kwh = self.tariff_p_per_kwh / 100.0;
+        (1..=self.analysis_years)
+            .map(|t| {
+                let t = t as f64;
+                yearly_loss_kwh * tariff_pounds_per_kwh * (1.0 + self.energy_escalation_rate).powf(t)
+                    / (1.0 + self.discount_rate).powf(t)
+            })
+            .sum()
+    }
+
+    /// First year by which the discounted cumulative energy saving of
+    /// `candidate` over `baseline` recovers `extra_capital_cost`, or `None`
+    /// if it never does within `analysis_years`.
+    pub fn payback_year(
+        &self,
+        baseline_yearly_loss_kwh: f64,
+        candidate_yearly_loss_kwh: f64,
+        extra_capital_cost: f64,
+    ) -> Option<u32> {
+        if extra_capital_cost <= 0.0 {
+            return Some(0);
+        }
+        let tariff_pounds_per_kwh = self.tariff_p_per_kwh / 100.0;
+        let yearly_saving_kwh = baseline_yearly_loss_kwh - candidate_yearly_loss_kwh;
+        let mut cumulative = 0.0;
+        for t in 1..=self.analysis_years {
+            let tf = t as f64;
+            cumulative += yearly_saving_kwh * tariff_pounds_per_kwh
+                * (1.0 + self.energy_escalation_rate).powf(tf)
+                / (1.0 + self.discount_rate).powf(tf);
+            if cumulative >= extra_capital_cost {
+                return Some(t);
+            }
+        }
+        None
+    }
+}
+
+/// One candidate cable size's lifecycle cost versus the baseline.
+#[derive(Debug, Clone, Copy)]
+pub struct CableOption {
+    pub csa_mm2: f64,
+    pub capital_cost: f64,
+    pub npv_energy_cost: f64,
+    /// Capital cost plus discounted energy-loss cost over the analysis period.
+    pub total_npv: f64,
+    pub payback_year: Option<u32>,
+}
+
+/// Ranks candidate cable sizes by total lifecycle cost (capital + discounted
+/// energy loss) against a baseline size, computing each candidate's payback
+/// year versus that baseline.
+pub fn compare_cable_options(
+    lifecycle: &LifecycleCost,
+    baseline_yearly_loss_kwh: f64,
+    baseline_capital_cost: f64,
+    candidates: &[(f64, f64, f64)],
+) -> Vec<CableOption> {
+    let mut options: Vec<CableOption> = candidates
+        .iter()
+        .map(|&(csa_mm2, yearly_loss_kwh, capital_cost)| {
+            let npv_energy_cost = lifecycle.npv_energy_cost(yearly_loss_kwh);
+            let payback_year = lifecycle.payback_year(
+                baseline_yearly_loss_kwh,
+                yearly_loss_kwh,
+                capital_cost - baseline_capital_cost,
+            );
+            CableOption {
+                csa_mm2,
+                capital_cost,
+                npv_energy_cost,
+                total_npv: capital_cost + npv_energy_cost,
+                payback_year,
+            }
+        })
+        .collect();
+
+    options.sort_by(|a, b| a.total_npv.partial_cmp(&b.total_npv).unwrap());
+    options
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_extra_capital_pays_back_immediately() {
+        let lifecycle = LifecycleCost::default();
+        assert_eq!(lifecycle.payback_year(100.0, 80.0, 0.0), Some(0));
+    }
+
+    #[test]
+    fn no_saving_never_pays_back() {
+        let lifecycle = LifecycleCost::default();
+        assert_eq!(lifecycle.payback_year(100.0, 100.0, 50.0), None);
+    }
+
+    #[test]
+    fn lowest_total_npv_ranks_first() {
+        let lifecycle = LifecycleCost::default();
+        let options = compare_cable_options(
+            &lifecycle,
+            500.0,
+            50.0,
+            &[(10.0, 500.0, 50.0), (16.0, 250.0, 80.0), (25.0, 150.0, 120.0)],
+        );
+        assert!(options[0].total_npv <= options[1].total_npv);
+        assert!(options[1].total_npv <= options[2].total_npv);
+    }
+}
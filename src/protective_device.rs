@@ -0,0 +1,391 @@
+//! Protective device time–current characteristics: BS 88, BS 3036 and
+//! BS 1361 fuses, and BS EN 60898 type B/C/D MCBs.
+//!
+//! Replaces the assumption that every fault is "580A for 5s" from one
+//! hard-coded fuse with the actual disconnection time a selected device
+//! gives at a given prospective fault current, so the adiabatic check
+//! validates a conductor against the real device curve.
+
+/// A tabulated (prospective current, disconnection time) point on a
+/// device's time–current characteristic.
+#[derive(Debug, Clone, Copy)]
+pub struct TimeCurrentPoint {
+    pub current_a: f64,
+    pub time_s: f64,
+}
+
+/// A fuse's time–current characteristic (BS 88, BS 3036, BS 1361), stored
+/// as points to interpolate between on a log-log scale (the customary
+/// representation for fuse curves, which are straight lines in log-log
+/// space).
+#[derive(Debug, Clone)]
+pub struct FuseCurve {
+    pub name: String,
+    pub rating_a: f64,
+    points: Vec<TimeCurrentPoint>,
+}
+
+impl FuseCurve {
+    /// Builds a fuse characteristic from tabulated points, sorted by
+    /// ascending current.
+    pub fn new(name: &str, rating_a: f64, mut points: Vec<TimeCurrentPoint>) -> Self {
+        points.sort_by(|a, b| a.current_a.partial_cmp(&b.current_a).unwrap());
+        Self {
+            name: name.to_string(),
+            rating_a,
+            points,
+        }
+    }
+
+    /// Looks up the disconnection time for `fault_current` by log-log
+    /// interpolation between tabulated points, clamping to 5s at the
+    /// low-current end (BS 7671's default maximum disconnection time for
+    /// distribution circuits) and to the fastest tabulated time above the
+    /// highest tabulated point.
+    pub fn disconnection_time(&self, fault_current: f64) -> f64 {
+        let Some(first) = self.points.first() else {
+            return 5.0;
+        };
+        if fault_current <= first.current_a {
+            return 5.0;
+        }
+        let last = self.points.last().unwrap();
+        if fault_current >= last.current_a {
+            return last.time_s;
+        }
+        for window in self.points.windows(2) {
+            let (lo, hi) = (window[0], window[1]);
+            if fault_current >= lo.current_a && fault_current <= hi.current_a {
+                let frac = (fault_current.ln() - lo.current_a.ln())
+                    / (hi.current_a.ln() - lo.current_a.ln());
+                return (lo.time_s.ln() + frac * (hi.time_s.ln() - lo.time_s.ln())).exp();
+            }
+        }
+        5.0
+    }
+}
+
+/// BS EN 60898 MCB type, determining the magnetic instantaneous-trip
+/// multiplier of the rated current `In`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum McbType {
+    /// Instantaneous trip guaranteed at 5× In (range 3–5×).
+    B,
+    /// Instantaneous trip guaranteed at 10× In (range 5–10×).
+    C,
+    /// Instantaneous trip guaranteed at 20× In (range 10–20×).
+    D,
+}
+
+impl McbType {
+    /// Multiplier of `In` above which instantaneous magnetic trip is
+    /// guaranteed across manufacturing tolerance.
+    fn instantaneous_multiplier(&self) -> f64 {
+        match self {
+            McbType::B => 5.0,
+            McbType::C => 10.0,
+            McbType::D => 20.0,
+        }
+    }
+}
+
+/// A BS EN 60898 MCB's time–current characteristic: instantaneous magnetic
+/// trip above its type's current threshold, and a tabulated thermal curve
+/// below it.
+#[derive(Debug, Clone)]
+pub struct Mcb {
+    pub name: String,
+    pub rating_a: f64,
+    pub mcb_type: McbType,
+    thermal_curve: FuseCurve,
+}
+
+/// Instantaneous trip time for an MCB operating magnetically.
+const MCB_INSTANTANEOUS_TIME_S: f64 = 0.1;
+
+impl Mcb {
+    /// Builds an MCB characteristic with a thermal curve for currents below
+    /// the magnetic instantaneous-trip threshold.
+    pub fn new(rating_a: f64, mcb_type: McbType, thermal_points: Vec<TimeCurrentPoint>) -> Self {
+        let type_letter = match mcb_type {
+            McbType::B => "B",
+            McbType::C => "C",
+            McbType::D => "D",
+        };
+        Self {
+            name: format!("BS EN 60898 Type {type_letter} {rating_a}A"),
+            rating_a,
+            mcb_type,
+            thermal_curve: FuseCurve::new("thermal", rating_a, thermal_points),
+        }
+    }
+
+    /// Minimum fault current guaranteeing instantaneous magnetic operation.
+    pub fn min_instantaneous_current(&self) -> f64 {
+        self.rating_a * self.mcb_type.instantaneous_multiplier()
+    }
+
+    /// Disconnection time: instantaneous (`0.1s`) above the magnetic
+    /// threshold, otherwise the tabulated thermal curve.
+    pub fn disconnection_time(&self, fault_current: f64) -> f64 {
+        if fault_current >= self.min_instantaneous_current() {
+            MCB_INSTANTANEOUS_TIME_S
+        } else {
+            self.thermal_curve.disconnection_time(fault_current)
+        }
+    }
+}
+
+/// A protective device: a fuse (BS 88, BS 3036, BS 1361) or an MCB
+/// (BS EN 60898 type B/C/D).
+#[derive(Debug, Clone)]
+pub enum ProtectiveDevice {
+    Fuse(FuseCurve),
+    Mcb(Mcb),
+}
+
+impl ProtectiveDevice {
+    /// BS 88-3 100A fuse, with indicative time–current points per BS 7671
+    /// Figure 3A1.
+    pub fn bs88_3_100a() -> Self {
+        ProtectiveDevice::Fuse(FuseCurve::new(
+            "BS 88-3 100A",
+            100.0,
+            vec![
+                TimeCurrentPoint { current_a: 290.0, time_s: 100.0 },
+                TimeCurrentPoint { current_a: 390.0, time_s: 20.0 },
+                TimeCurrentPoint { current_a: 580.0, time_s: 5.0 },
+                TimeCurrentPoint { current_a: 1100.0, time_s: 0.4 },
+                TimeCurrentPoint { current_a: 1500.0, time_s: 0.1 },
+            ],
+        ))
+    }
+
+    /// BS 88-2 63A fuse, with indicative time–current points per BS 7671
+    /// Figure 3A2.
+    pub fn bs88_2_63a() -> Self {
+        ProtectiveDevice::Fuse(FuseCurve::new(
+            "BS 88-2 63A",
+            63.0,
+            vec![
+                TimeCurrentPoint { current_a: 170.0, time_s: 100.0 },
+                TimeCurrentPoint { current_a: 230.0, time_s: 20.0 },
+                TimeCurrentPoint { current_a: 320.0, time_s: 5.0 },
+                TimeCurrentPoint { current_a: 650.0, time_s: 0.4 },
+                TimeCurrentPoint { current_a: 900.0, time_s: 0.1 },
+            ],
+        ))
+    }
+
+    /// BS 3036 semi-enclosed rewirable fuse, which operates considerably
+    /// slower than an equivalent BS 88 cartridge fuse for the same rating.
+    pub fn bs3036_rewirable(rating_a: f64) -> Self {
+        ProtectiveDevice::Fuse(FuseCurve::new(
+            &format!("BS 3036 {rating_a}A"),
+            rating_a,
+            vec![
+                TimeCurrentPoint { current_a: 2.0 * rating_a, time_s: 100.0 },
+                TimeCurrentPoint { current_a: 3.0 * rating_a, time_s: 20.0 },
+                TimeCurrentPoint { current_a: 4.0 * rating_a, time_s: 5.0 },
+                TimeCurrentPoint { current_a: 7.0 * rating_a, time_s: 0.4 },
+                TimeCurrentPoint { current_a: 10.0 * rating_a, time_s: 0.1 },
+            ],
+        ))
+    }
+
+    /// BS 1361 domestic cartridge fuse, with indicative time–current points.
+    pub fn bs1361_fuse(rating_a: f64) -> Self {
+        ProtectiveDevice::Fuse(FuseCurve::new(
+            &format!("BS 1361 {rating_a}A"),
+            rating_a,
+            vec![
+                TimeCurrentPoint { current_a: 2.2 * rating_a, time_s: 100.0 },
+                TimeCurrentPoint { current_a: 2.8 * rating_a, time_s: 20.0 },
+                TimeCurrentPoint { current_a: 3.8 * rating_a, time_s: 5.0 },
+                TimeCurrentPoint { current_a: 7.0 * rating_a, time_s: 0.4 },
+                TimeCurrentPoint { current_a: 10.0 * rating_a, time_s: 0.1 },
+            ],
+        ))
+    }
+
+    /// BS EN 60898 MCB of the given rating and type (B, C or D), with an
+    /// indicative thermal curve below the magnetic instantaneous threshold.
+    pub fn mcb(rating_a: f64, mcb_type: McbType) -> Self {
+        ProtectiveDevice::Mcb(Mcb::new(
+            rating_a,
+            mcb_type,
+            vec![
+                TimeCurrentPoint { current_a: 1.45 * rating_a, time_s: 3600.0 },
+                TimeCurrentPoint { current_a: 2.0 * rating_a, time_s: 60.0 },
+                TimeCurrentPoint { current_a: 3.0 * rating_a, time_s: 5.0 },
+            ],
+        ))
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            ProtectiveDevice::Fuse(fuse) => &fuse.name,
+            ProtectiveDevice::Mcb(mcb) => &mcb.name,
+        }
+    }
+
+    pub fn rating_a(&self) -> f64 {
+        match self {
+            ProtectiveDevice::Fuse(fuse) => fuse.rating_a,
+            ProtectiveDevice::Mcb(mcb) => mcb.rating_a,
+        }
+    }
+
+    /// Disconnection time for `fault_current`. Fuses always return a time
+    /// (clamped at the low-current end); MCBs return `None` below their
+    /// thermal curve's lowest tabulated point, since at that point they are
+    /// not guaranteed to trip within a bounded time.
+    pub fn disconnection_time(&self, fault_current: f64) -> Option<f64> {
+        match self {
+            ProtectiveDevice::Fuse(fuse) => Some(fuse.disconnection_time(fault_current)),
+            ProtectiveDevice::Mcb(mcb) => {
+                if fault_current >= mcb.min_instantaneous_current() {
+                    Some(MCB_INSTANTANEOUS_TIME_S)
+                } else if fault_current >= mcb.thermal_curve.points.first()?.current_a {
+                    Some(mcb.disconnection_time(fault_current))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Minimum fault current guaranteeing instantaneous magnetic operation,
+    /// for an MCB. `None` for fuses, which have no magnetic trip stage.
+    pub fn min_instantaneous_current(&self) -> Option<f64> {
+        match self {
+            ProtectiveDevice::Fuse(_) => None,
+            ProtectiveDevice::Mcb(mcb) => Some(mcb.min_instantaneous_current()),
+        }
+    }
+}
+
+/// A conductor's adiabatic withstand margin over the minimum required CSA.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Margin {
+    pub required_csa_mm2: f64,
+    pub actual_csa_mm2: f64,
+}
+
+/// A conductor's CSA is below the minimum required by the adiabatic equation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Inadequate {
+    pub required_csa_mm2: f64,
+    pub actual_csa_mm2: f64,
+}
+
+/// Verifies a conductor's CSA against the adiabatic equation using the real
+/// disconnection time `device` gives at `fault_current`, rather than an
+/// assumed fixed fault current and duration. A device that does not
+/// disconnect within a bounded time (`None`) can never satisfy the
+/// adiabatic equation, so this is reported as `Inadequate`.
+pub fn verify_adiabatic(
+    csa_mm2: f64,
+    fault_current: f64,
+    device: &ProtectiveDevice,
+    k_factor: f64,
+) -> Result<Margin, Inadequate> {
+    let Some(disconnection_time) = device.disconnection_time(fault_current) else {
+        return Err(Inadequate {
+            required_csa_mm2: f64::INFINITY,
+            actual_csa_mm2: csa_mm2,
+        });
+    };
+    let required_csa_mm2 = (fault_current * disconnection_time.sqrt()) / k_factor;
+    if csa_mm2 >= required_csa_mm2 {
+        Ok(Margin { required_csa_mm2, actual_csa_mm2: csa_mm2 })
+    } else {
+        Err(Inadequate { required_csa_mm2, actual_csa_mm2: csa_mm2 })
+    }
+}
+
+/// Let-through energy `I²t` for a fault of `fault_current` lasting
+/// `disconnection_time_s`, in A²s.
+pub fn let_through_energy(fault_current: f64, disconnection_time_s: f64) -> f64 {
+    fault_current * fault_current * disconnection_time_s
+}
+
+/// Checks a conductor's short-circuit thermal withstand directly against a
+/// device's published let-through energy: `I²t <= k²·S²`. This is the same
+/// adiabatic requirement as `verify_adiabatic`, but expressed in energy
+/// terms, which is how current-limiting devices (e.g. BS 88 fuses, which
+/// cut off let-through `I²t` well below `I²·t_nominal` on a high fault
+/// current) publish their withstand data.
+pub fn thermal_stress_ok(let_through_i2t: f64, k_factor: f64, csa_mm2: f64) -> bool {
+    let_through_i2t <= k_factor * k_factor * csa_mm2 * csa_mm2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disconnection_time_matches_tabulated_point() {
+        let device = ProtectiveDevice::bs88_3_100a();
+        assert!((device.disconnection_time(580.0).unwrap() - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn disconnection_time_clamps_below_lowest_point() {
+        let device = ProtectiveDevice::bs88_3_100a();
+        assert_eq!(device.disconnection_time(100.0), Some(5.0));
+    }
+
+    #[test]
+    fn disconnection_time_interpolates_between_points() {
+        let device = ProtectiveDevice::bs88_3_100a();
+        let time = device.disconnection_time(700.0).unwrap();
+        assert!(time < 5.0 && time > 0.4);
+    }
+
+    #[test]
+    fn verify_adiabatic_rejects_undersized_conductor() {
+        let device = ProtectiveDevice::bs88_3_100a();
+        assert!(verify_adiabatic(4.0, 580.0, &device, 143.0).is_err());
+    }
+
+    #[test]
+    fn verify_adiabatic_accepts_adequate_conductor() {
+        let device = ProtectiveDevice::bs88_3_100a();
+        assert!(verify_adiabatic(10.0, 580.0, &device, 143.0).is_ok());
+    }
+
+    #[test]
+    fn mcb_trips_instantaneously_above_threshold() {
+        let device = ProtectiveDevice::mcb(32.0, McbType::B);
+        assert_eq!(device.min_instantaneous_current(), Some(160.0));
+        assert_eq!(device.disconnection_time(200.0), Some(0.1));
+    }
+
+    #[test]
+    fn mcb_below_thermal_curve_never_trips_in_bounded_time() {
+        let device = ProtectiveDevice::mcb(32.0, McbType::B);
+        assert_eq!(device.disconnection_time(1.0), None);
+    }
+
+    #[test]
+    fn type_d_mcb_requires_a_higher_fault_current_to_trip_instantaneously() {
+        let type_b = ProtectiveDevice::mcb(32.0, McbType::B);
+        let type_d = ProtectiveDevice::mcb(32.0, McbType::D);
+        assert!(
+            type_d.min_instantaneous_current().unwrap() > type_b.min_instantaneous_current().unwrap()
+        );
+    }
+
+    #[test]
+    fn let_through_energy_matches_i2t() {
+        assert!((let_through_energy(580.0, 5.0) - 580.0 * 580.0 * 5.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn thermal_stress_ok_matches_verify_adiabatic() {
+        let i2t = let_through_energy(580.0, 5.0);
+        assert!(thermal_stress_ok(i2t, 143.0, 10.0));
+        assert!(!thermal_stress_ok(i2t, 143.0, 4.0));
+    }
+}
@@ -0,0 +1,210 @@
+//! Temperature-corrected AC conductor resistance model (IEC 60287-1-1).
+//!
+//! Replaces a literal mV/A/m figure copied from a manufacturer's table with
+//! one derived from conductor geometry, operating temperature and supply
+//! frequency, so voltage-drop calculations stay accurate when a conductor
+//! is run hotter than the table's reference temperature.
+
+/// Conductor material, with its DC resistivity and temperature coefficient
+/// of resistance at 20 °C (IEC 60287-1-1 Table 1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConductorMaterial {
+    Copper,
+    Aluminium,
+    /// Galvanized steel, as used for earthing conductors and SWA armour
+    /// (BS 7430).
+    Steel,
+}
+
+impl ConductorMaterial {
+    /// Resistivity at 20 °C, in Ω·m.
+    pub fn resistivity_20c(&self) -> f64 {
+        match self {
+            ConductorMaterial::Copper => 1.724e-8,
+            ConductorMaterial::Aluminium => 2.826e-8,
+            ConductorMaterial::Steel => 1.38e-7,
+        }
+    }
+
+    /// Temperature coefficient of resistance at 20 °C, per Kelvin.
+    pub fn temp_coefficient_20c(&self) -> f64 {
+        match self {
+            ConductorMaterial::Copper => 0.00393,
+            ConductorMaterial::Aluminium => 0.00403,
+            ConductorMaterial::Steel => 0.0045,
+        }
+    }
+}
+
+/// Number of phases, used to turn `Rac` into an mV/A/m figure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phases {
+    Single,
+    Three,
+}
+
+/// Inputs to the IEC 60287-1-1 resistance chain for one conductor run.
+#[derive(Debug, Clone, Copy)]
+pub struct ResistanceModel {
+    pub material: ConductorMaterial,
+    /// Conductor cross-sectional area, in mm².
+    pub csa_mm2: f64,
+    /// Conductor operating temperature, in °C.
+    pub operating_temp_c: f64,
+    /// Supply frequency, in Hz.
+    pub frequency_hz: f64,
+    /// Centre-to-centre conductor spacing, in mm.
+    pub spacing_mm: f64,
+}
+
+impl ResistanceModel {
+    /// DC resistance at 20 °C, `R20 = ρ20 · l / A`, in Ω/m (l = 1 m).
+    pub fn r20(&self) -> f64 {
+        self.material.resistivity_20c() / (self.csa_mm2 * 1e-6)
+    }
+
+    /// DC resistance corrected to `operating_temp_c`:
+    /// `Rθ = R20 · [1 + α20 · (θ − 20)]`, in Ω/m.
+    pub fn r_dc(&self) -> f64 {
+        let alpha20 = self.material.temp_coefficient_20c();
+        self.r20() * (1.0 + alpha20 * (self.operating_temp_c - 20.0))
+    }
+
+    /// Equivalent circular conductor diameter, in mm, used by the skin and
+    /// proximity effect terms.
+    fn conductor_diameter_mm(&self) -> f64 {
+        (4.0 * self.csa_mm2 / std::f64::consts::PI).sqrt()
+    }
+
+    /// Skin-effect argument `xs² = (8πf/Rdc) · 10⁻⁷ · ks` (ks ≈ 1 for round
+    /// stranded conductors).
+    fn skin_effect_x2(&self) -> f64 {
+        const KS: f64 = 1.0;
+        (8.0 * std::f64::consts::PI * self.frequency_hz / self.r_dc()) * 1e-7 * KS
+    }
+
+    /// Skin-effect loss factor `ys = xs⁴ / (192 + 0.8·xs⁴)`.
+    pub fn skin_effect_factor(&self) -> f64 {
+        let xs2 = self.skin_effect_x2();
+        let xs4 = xs2 * xs2;
+        xs4 / (192.0 + 0.8 * xs4)
+    }
+
+    /// Proximity-effect loss factor `yp`, of the same form as `ys` but
+    /// scaled by `(dc/s)²` (a simplified form of the full IEC 60287-1-1
+    /// proximity term, adequate for the spacings used on garage/domestic
+    /// sub-mains).
+    pub fn proximity_effect_factor(&self) -> f64 {
+        let ratio = self.conductor_diameter_mm() / self.spacing_mm;
+        self.skin_effect_factor() * ratio * ratio
+    }
+
+    /// AC resistance `Rac = Rdc · (1 + ys + yp)`, in Ω/m.
+    pub fn r_ac(&self) -> f64 {
+        self.r_dc() * (1.0 + self.skin_effect_factor() + self.proximity_effect_factor())
+    }
+
+    /// Conductor reactance `X = 2πf · 0.2·ln(2s/d) × 10⁻⁶` (BS 7671 On-Site
+    /// Guide), in Ω/m, for cores spaced `spacing_mm` apart.
+    pub fn x_ac(&self) -> f64 {
+        let d = self.conductor_diameter_mm();
+        2.0 * std::f64::consts::PI * self.frequency_hz * 0.2e-6 * (2.0 * self.spacing_mm / d).ln()
+    }
+
+    /// Derives mV/A/m from `Rac`: `√3 · 1000 · Rac` for three-phase,
+    /// `2000 · Rac` for single-phase.
+    pub fn mv_per_amp_per_meter(&self, phases: Phases) -> f64 {
+        match phases {
+            Phases::Three => 3f64.sqrt() * 1000.0 * self.r_ac(),
+            Phases::Single => 2000.0 * self.r_ac(),
+        }
+    }
+}
+
+/// AC voltage drop including both the resistive and reactive components of
+/// impedance at the load power factor: `(R·cosφ + X·sinφ)·I·L`, replacing
+/// the resistive-only `mV/A/m × I × L` figure, which understates the drop
+/// for loads with a significant reactive component. `r_component` and
+/// `x_component` are per-metre Ω values (e.g. `r_ac()`/`x_ac()`); `phases`
+/// applies BS 7671's usual √3 (three-phase) or ×2 (single-phase,
+/// out-and-back) multiplier.
+pub fn voltage_drop_ac(
+    r_component: f64,
+    x_component: f64,
+    current: f64,
+    length_m: f64,
+    power_factor: f64,
+    phases: Phases,
+) -> f64 {
+    let sin_phi = (1.0 - power_factor * power_factor).max(0.0).sqrt();
+    let phase_factor = match phases {
+        Phases::Three => 3f64.sqrt(),
+        Phases::Single => 2.0,
+    };
+    phase_factor * (r_component * power_factor + x_component * sin_phi) * current * length_m
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn r20_matches_resistivity_over_area() {
+        let model = ResistanceModel {
+            material: ConductorMaterial::Copper,
+            csa_mm2: 10.0,
+            operating_temp_c: 20.0,
+            frequency_hz: 50.0,
+            spacing_mm: 20.0,
+        };
+        // R20 = 1.724e-8 / 10e-6 = 1.724e-3 Ω/m
+        assert!((model.r20() - 1.724e-3).abs() < 1e-6);
+    }
+
+    #[test]
+    fn r_dc_rises_with_temperature() {
+        let cold = ResistanceModel {
+            material: ConductorMaterial::Copper,
+            csa_mm2: 10.0,
+            operating_temp_c: 20.0,
+            frequency_hz: 50.0,
+            spacing_mm: 20.0,
+        };
+        let hot = ResistanceModel {
+            operating_temp_c: 70.0,
+            ..cold
+        };
+        assert!(hot.r_dc() > cold.r_dc());
+    }
+
+    #[test]
+    fn single_phase_mv_per_amp_is_higher_than_three_phase() {
+        let model = ResistanceModel {
+            material: ConductorMaterial::Copper,
+            csa_mm2: 10.0,
+            operating_temp_c: 70.0,
+            frequency_hz: 50.0,
+            spacing_mm: 20.0,
+        };
+        // mV/A/m uses the per-phase multiplier (2000·Rac single, √3·1000·Rac
+        // three-phase) on the same Rac, so single-phase is always the larger
+        // figure - matching real BS 7671 Appendix 4 tables.
+        assert!(
+            model.mv_per_amp_per_meter(Phases::Single) > model.mv_per_amp_per_meter(Phases::Three)
+        );
+    }
+
+    #[test]
+    fn voltage_drop_ac_at_unity_power_factor_ignores_reactance() {
+        let drop = voltage_drop_ac(0.002, 0.0008, 32.0, 45.0, 1.0, Phases::Single);
+        let resistive_only = 2.0 * 0.002 * 32.0 * 45.0;
+        assert!((drop - resistive_only).abs() < 1e-9);
+    }
+
+    #[test]
+    fn reactance_dominated_runs_drop_more_at_lower_power_factor() {
+        let high_pf = voltage_drop_ac(0.0005, 0.003, 32.0, 45.0, 1.0, Phases::Single);
+        let low_pf = voltage_drop_ac(0.0005, 0.003, 32.0, 45.0, 0.8, Phases::Single);
+        assert!(low_pf > high_pf);
+    }
+}